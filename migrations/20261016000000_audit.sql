@@ -0,0 +1,16 @@
+-- Add migration script here
+
+-- Append-only trail of which authenticated user stored or voided a Statement.
+-- Only populated when LaRS is running in User or Auth mode.
+-- The `action` column is 0 for an insert and 1 for a void.
+CREATE TABLE IF NOT EXISTS audit (
+    id SERIAL NOT NULL PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    action SMALLINT NOT NULL,
+    statement_uuid UUID NOT NULL,
+    created TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT fk93 FOREIGN KEY(user_id) REFERENCES users(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_statement ON audit(statement_uuid);
@@ -0,0 +1,125 @@
+-- Add migration script here
+
+-- Verbs and Activities are matched by a normalized form of their IRI (w/ a
+-- lower-case scheme and, when it's all ASCII, host) held in `iri_key`, while
+-- `iri` keeps the form first received.
+
+-- Compute the normalized form of an IRI. MUST be kept in sync w/ `iri_key()`
+-- in src/db/mod.rs which applies it to new rows.
+CREATE OR REPLACE FUNCTION normalize_iri(iri TEXT) RETURNS TEXT AS $$
+DECLARE
+    m TEXT[];
+BEGIN
+    m := regexp_match(iri, '^([A-Za-z][A-Za-z0-9+.-]*):(//([^/?#@]*@)?([^/?#]*))?(.*)$');
+    IF m IS NULL THEN
+        RETURN iri;
+    END IF;
+    RETURN lower(m[1]) || ':'
+        || CASE
+            WHEN m[2] IS NULL THEN ''
+            WHEN m[4] ~ '^[\x01-\x7F]*$' THEN '//' || COALESCE(m[3], '') || lower(m[4])
+            ELSE '//' || COALESCE(m[3], '') || m[4]
+        END
+        || m[5];
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+-- the same IRI may now be stored in different forms...
+ALTER TABLE verb DROP CONSTRAINT IF EXISTS verb_iri_key;
+ALTER TABLE activity DROP CONSTRAINT IF EXISTS activity_iri_key;
+
+ALTER TABLE verb ADD COLUMN IF NOT EXISTS iri_key TEXT;
+UPDATE verb SET iri_key = normalize_iri(iri) WHERE iri_key IS NULL;
+ALTER TABLE verb ALTER COLUMN iri_key SET NOT NULL;
+
+ALTER TABLE activity ADD COLUMN IF NOT EXISTS iri_key TEXT;
+UPDATE activity SET iri_key = normalize_iri(iri) WHERE iri_key IS NULL;
+ALTER TABLE activity ALTER COLUMN iri_key SET NOT NULL;
+
+-- rows w/ the same key are merged into the one w/ the lowest ID. `display`
+-- and `definition` values of the kept row take precedence...
+CREATE TEMPORARY TABLE verb_dup AS
+SELECT id, keep FROM (SELECT id, MIN(id) OVER (PARTITION BY iri_key) AS keep FROM verb) x
+WHERE id <> keep;
+
+UPDATE verb v SET display = x.display || COALESCE(v.display, '{}'::JSONB)
+FROM (
+    SELECT d.keep, jsonb_object_agg(e.key, e.value) AS display
+    FROM verb_dup d JOIN verb y ON y.id = d.id, jsonb_each(y.display) e
+    GROUP BY d.keep
+) x
+WHERE v.id = x.keep;
+
+UPDATE statement s SET verb_id = d.keep FROM verb_dup d WHERE s.verb_id = d.id;
+DELETE FROM verb v USING verb_dup d WHERE v.id = d.id;
+
+CREATE TEMPORARY TABLE activity_dup AS
+SELECT id, keep FROM (SELECT id, MIN(id) OVER (PARTITION BY iri_key) AS keep FROM activity) x
+WHERE id <> keep;
+
+UPDATE activity a SET definition = x.definition || COALESCE(a.definition, '{}'::JSONB)
+FROM (
+    SELECT d.keep, jsonb_object_agg(e.key, e.value) AS definition
+    FROM activity_dup d JOIN activity y ON y.id = d.id, jsonb_each(y.definition) e
+    GROUP BY d.keep
+) x
+WHERE a.id = x.keep;
+
+-- in tables where the activity is part of the primary key, re-pointing a row
+-- may collide w/ another.  keep the one referencing the lowest activity ID...
+DELETE FROM obj_activity t USING activity_dup d
+WHERE t.activity_id = d.id AND EXISTS (
+    SELECT 1 FROM obj_activity o LEFT JOIN activity_dup od ON od.id = o.activity_id
+    WHERE o.statement_id = t.statement_id
+      AND COALESCE(od.keep, o.activity_id) = d.keep
+      AND o.activity_id < t.activity_id);
+UPDATE obj_activity t SET activity_id = d.keep FROM activity_dup d WHERE t.activity_id = d.id;
+
+DELETE FROM ctx_activities t USING activity_dup d
+WHERE t.activity_id = d.id AND EXISTS (
+    SELECT 1 FROM ctx_activities o LEFT JOIN activity_dup od ON od.id = o.activity_id
+    WHERE o.context_id = t.context_id AND o.kind = t.kind
+      AND COALESCE(od.keep, o.activity_id) = d.keep
+      AND o.activity_id < t.activity_id);
+UPDATE ctx_activities t SET activity_id = d.keep FROM activity_dup d WHERE t.activity_id = d.id;
+
+-- State and Activity Profile documents are never dropped. abort instead if
+-- re-pointing them would make two documents share the same key...
+DO $$
+DECLARE
+    conflicts TEXT;
+BEGIN
+    SELECT string_agg(format('(activity #%s, agent #%s, registration %s, stateId %L)',
+                             x.activity_id, x.agent_id, x.registration, x.state_id), ', ')
+    INTO conflicts
+    FROM (
+        SELECT COALESCE(d.keep, t.activity_id) AS activity_id, t.agent_id, t.registration, t.state_id
+        FROM state t LEFT JOIN activity_dup d ON d.id = t.activity_id
+        GROUP BY 1, 2, 3, 4 HAVING COUNT(*) > 1
+    ) x;
+    IF conflicts IS NOT NULL THEN
+        RAISE EXCEPTION 'Failed merging Activities w/ the same normalized IRI. Conflicting State documents: %. Remove or rename all but one of each then re-run', conflicts;
+    END IF;
+
+    SELECT string_agg(format('(activity #%s, profileId %L)', x.activity_id, x.profile_id), ', ')
+    INTO conflicts
+    FROM (
+        SELECT COALESCE(d.keep, t.activity_id) AS activity_id, t.profile_id
+        FROM activity_profile t LEFT JOIN activity_dup d ON d.id = t.activity_id
+        GROUP BY 1, 2 HAVING COUNT(*) > 1
+    ) x;
+    IF conflicts IS NOT NULL THEN
+        RAISE EXCEPTION 'Failed merging Activities w/ the same normalized IRI. Conflicting Activity Profile documents: %. Remove or rename all but one of each then re-run', conflicts;
+    END IF;
+END;
+$$;
+UPDATE state t SET activity_id = d.keep FROM activity_dup d WHERE t.activity_id = d.id;
+UPDATE activity_profile t SET activity_id = d.keep FROM activity_dup d WHERE t.activity_id = d.id;
+
+DELETE FROM activity a USING activity_dup d WHERE a.id = d.id;
+
+DROP TABLE verb_dup;
+DROP TABLE activity_dup;
+
+ALTER TABLE verb ADD CONSTRAINT verb_iri_key_key UNIQUE (iri_key);
+ALTER TABLE activity ADD CONSTRAINT activity_iri_key_key UNIQUE (iri_key);
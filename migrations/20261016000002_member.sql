@@ -0,0 +1,108 @@
+-- Add migration script here
+
+-- Groups stored before their members were recorded have no `member` rows,
+-- which the `agent` filter relies on. Members are only kept in the `exact`
+-- JSON of the Statements referencing those Groups, so pair every Group row
+-- w/ the JSON of the Statement property it was stored from, and link each
+-- of its members to the Agent row sharing their IFI.
+--
+-- Agents never stored on their own get a row w/ a placeholder fingerprint
+-- derived from their IFI; `find_actor_id()` in src/db/actor.rs replaces it
+-- w/ the real one the next time it sees them.
+--
+-- NOTE (rsn) 20261016 - Groups referenced only from a Context's
+-- `contextGroups` are not backfilled; their rows can't be paired w/ their
+-- JSON reliably.
+
+CREATE TEMPORARY TABLE group_member ON COMMIT DROP AS
+WITH refs (group_id, js) AS (
+    SELECT s.actor_id, s.exact->'actor' FROM statement s
+    UNION ALL
+    SELECT s.authority_id, s.exact->'authority' FROM statement s
+    UNION ALL
+    SELECT o.actor_id, s.exact->'object'
+    FROM obj_actor o JOIN statement s ON s.id = o.statement_id
+    UNION ALL
+    SELECT c.instructor_id, s.exact->'context'->'instructor'
+    FROM statement s JOIN context c ON c.id = s.context_id
+    UNION ALL
+    SELECT c.team_id, s.exact->'context'->'team'
+    FROM statement s JOIN context c ON c.id = s.context_id
+    -- a SubStatement's JSON is the `object` of the Statement containing it...
+    UNION ALL
+    SELECT x.actor_id, s.exact->'object'->'actor'
+    FROM obj_statement os
+        JOIN statement s ON s.id = os.statement_id
+        JOIN statement x ON x.id = os.sub_statement_id
+    UNION ALL
+    SELECT o.actor_id, s.exact->'object'->'object'
+    FROM obj_statement os
+        JOIN statement s ON s.id = os.statement_id
+        JOIN obj_actor o ON o.statement_id = os.sub_statement_id
+    UNION ALL
+    SELECT c.instructor_id, s.exact->'object'->'context'->'instructor'
+    FROM obj_statement os
+        JOIN statement s ON s.id = os.statement_id
+        JOIN statement x ON x.id = os.sub_statement_id
+        JOIN context c ON c.id = x.context_id
+    UNION ALL
+    SELECT c.team_id, s.exact->'object'->'context'->'team'
+    FROM obj_statement os
+        JOIN statement s ON s.id = os.statement_id
+        JOIN statement x ON x.id = os.sub_statement_id
+        JOIN context c ON c.id = x.context_id
+), members (group_id, member) AS (
+    SELECT DISTINCT r.group_id, m.member
+    FROM refs r
+        JOIN actor a ON a.id = r.group_id AND a.is_group
+        CROSS JOIN LATERAL jsonb_array_elements(
+            CASE WHEN jsonb_typeof(r.js->'member') = 'array' THEN r.js->'member' END
+        ) AS m (member)
+)
+-- MUST be kept in sync w/ how `find_actor_id()` stores IFI values...
+SELECT DISTINCT m.group_id, k.kind, k.value, m.member->>'name' AS name
+FROM members m CROSS JOIN LATERAL (
+    SELECT 0::SMALLINT, regexp_replace(m.member->>'mbox', '^mailto:', '')
+    WHERE m.member ? 'mbox'
+    UNION ALL
+    SELECT 1::SMALLINT, m.member->>'mbox_sha1sum'
+    WHERE m.member ? 'mbox_sha1sum'
+    UNION ALL
+    SELECT 2::SMALLINT, m.member->>'openid'
+    WHERE m.member ? 'openid'
+    UNION ALL
+    SELECT 3::SMALLINT, (m.member->'account'->>'homePage') || '~' || (m.member->'account'->>'name')
+    WHERE m.member ? 'account'
+) AS k (kind, value);
+
+INSERT INTO ifi (kind, value)
+SELECT DISTINCT kind, value FROM group_member
+ON CONFLICT (kind, value) DO NOTHING;
+
+WITH missing (ifi_id, fp, name) AS (
+    SELECT DISTINCT ON (i.id)
+        i.id,
+        ('x' || left(md5(i.kind || ':' || i.value), 16))::BIT(64)::BIGINT,
+        g.name
+    FROM group_member g JOIN ifi i ON i.kind = g.kind AND i.value = g.value
+    WHERE NOT EXISTS (
+        SELECT 1 FROM actor_ifi ai JOIN actor a ON a.id = ai.actor_id
+        WHERE ai.ifi_id = i.id AND NOT a.is_group
+    )
+    ORDER BY i.id, g.name
+), added (id, fp) AS (
+    INSERT INTO actor (fp, name, is_group)
+    SELECT fp, name, FALSE FROM missing
+    ON CONFLICT (fp) DO NOTHING
+    RETURNING id, fp
+)
+INSERT INTO actor_ifi (actor_id, ifi_id)
+SELECT a.id, m.ifi_id FROM added a JOIN missing m ON m.fp = a.fp;
+
+INSERT INTO member (group_id, agent_id)
+SELECT DISTINCT g.group_id, a.id
+FROM group_member g
+    JOIN ifi i ON i.kind = g.kind AND i.value = g.value
+    JOIN actor_ifi ai ON ai.ifi_id = i.id
+    JOIN actor a ON a.id = ai.actor_id AND NOT a.is_group
+ON CONFLICT DO NOTHING;
@@ -4,6 +4,8 @@ use crate::{Mode, MyLanguageTag};
 use base64::{prelude::BASE64_STANDARD, Engine};
 use chrono::TimeDelta;
 use dotenvy::var;
+use iri_string::types::IriString;
+use rocket::data::ByteUnit;
 use std::{
     num::NonZeroUsize,
     path::{self, Path, PathBuf},
@@ -21,6 +23,14 @@ const DEFAULT_TTL_INTERVAL_SECS: &str = "60";
 
 const DEFAULT_MFC_INTERVAL_SECS: &str = "10";
 
+const DEFAULT_UPLOAD_TTL_SECS: &str = "3600";
+
+const DEFAULT_MAX_NAME_LENGTH: &str = "1024";
+
+const DEFAULT_MAX_JSON_DEPTH: &str = "64";
+
+const DEFAULT_DB_MAX_STATEMENTS_PAGE_LEN: &str = "100";
+
 const DEPRECATION_MSG1: &str =
     "LRS_AUTHORITY_IFI is now deprecated and will be removed in future release.\nUse LRS_ROOT_EMAIL instead.";
 
@@ -41,6 +51,12 @@ pub struct Config {
     pub(crate) db_idle_timeout: Duration,
     pub(crate) db_max_lifetime: Duration,
     pub(crate) db_statements_page_len: i32,
+    /// Upper bound of the page size a client may request through the
+    /// `X-Experience-API-Page-Size` header.
+    pub db_max_statements_page_len: i32,
+    /// Maximum number of Statements from the same request inserted in the
+    /// `statement` table w/ one multi-row `INSERT`.
+    pub insert_batch_size: usize,
 
     /// The base of this server's external URL as seen by its users.
     pub external_url: String,
@@ -51,6 +67,7 @@ pub struct Config {
     pub(crate) root_email: String,
     pub(crate) root_credentials: Option<u32>,
     pub(crate) user_cache_len: NonZeroUsize,
+    pub(crate) verb_cache_len: NonZeroUsize,
 
     pub(crate) ttl_batch_len: i32,
     pub(crate) ttl: TimeDelta,
@@ -60,6 +77,15 @@ pub struct Config {
 
     pub(crate) default_language: String,
 
+    /// Maximum number of characters allowed in an Agent's or Group's `name`, or
+    /// in any of the values of an Activity definition's `name` Language Map.
+    pub max_name_length: usize,
+
+    /// Maximum nesting depth of an incoming Statement's JSON, incl. the
+    /// contents of its `extensions`. Note that regardless of this value
+    /// `serde_json` refuses to parse documents nested more than 128 levels.
+    pub max_json_depth: usize,
+
     /// Boolean flag that controls how a Statement's JWS signature is processed.
     ///
     /// When `false` a _Statement_ is deemed to be correcly signed if it's
@@ -75,6 +101,57 @@ pub struct Config {
     /// 4. The JWS signature correctly matches the same generated using the RSA
     ///    Public Key contained in the 1st certificate.
     pub jws_strict: bool,
+
+    /// Boolean flag that when `true` causes Statements w/ Attachments whose
+    /// `fileUrl` is not an HTTPS URL to be rejected. Default is `false`.
+    pub https_file_urls: bool,
+
+    /// Boolean flag that when `true` enables the `/metrics` end-point exposing
+    /// server counters in Prometheus text format. Default is `false`.
+    pub metrics_enabled: bool,
+
+    /// Boolean flag that when `true` enables the end-point for uploading
+    /// Attachments' contents in ranged chunks, ahead of POSTing or PUTting the
+    /// Statement(s) referencing them. Default is `false`.
+    pub resumable_uploads: bool,
+    pub(crate) upload_ttl: u64,
+
+    /// Value of the `Cache-Control` header sent w/ State, Agent Profile and
+    /// Activity Profile documents (and lists of their IDs). Default is
+    /// `no-cache`; i.e. clients may keep a copy but must revalidate it w/ its
+    /// ETag before use.
+    pub doc_cache_control: String,
+
+    /// Boolean flag that when `true` causes Statements carrying a `stored`
+    /// property to be rejected w/ a 400 (Bad Request). When `false` (the
+    /// default) such a value is silently discarded since `stored` is always
+    /// assigned by the LRS.
+    pub reject_client_stored: bool,
+
+    /// Maximum size of an `application/json` Statement resource request body;
+    /// i.e. Statement(s) to PUT or POST. Larger ones are rejected w/ a 413
+    /// (Payload Too Large). Default is 2 MiB.
+    pub json_limit: ByteUnit,
+
+    /// Maximum size of a `multipart/mixed` request body; i.e. Statement(s)
+    /// and their Attachments' contents. Larger ones are rejected w/ a 413
+    /// (Payload Too Large). Default is 10 MiB.
+    pub multipart_limit: ByteUnit,
+
+    /// Boolean flag that when `true` adds an `X-Response-Time-Ms` header to
+    /// every response w/ the time it took to process the request. Default
+    /// is `false`.
+    pub response_time_header: bool,
+
+    /// Boolean flag that when `true` causes Statements whose JSON contains an
+    /// Object w/ the same key more than once to be rejected w/ a 400 (Bad
+    /// Request). When `false` (the default) the last value wins.
+    pub reject_duplicate_keys: bool,
+
+    /// IRIs of the only Extensions keys allowed in incoming Statements. When
+    /// not empty, Statements w/ any other Extensions key are rejected w/ a
+    /// 400 (Bad Request). Default is empty; i.e. any key is allowed.
+    pub extensions_allowlist: Vec<IriString>,
 }
 
 impl Default for Config {
@@ -82,14 +159,24 @@ impl Default for Config {
         let db_server_url = var("DB_SERVER_URL").expect("Missing DB_SERVERL_URL");
         let db_name = var("DB_NAME").expect("Missing DB_NAME");
 
-        let db_max_connections: u32 = var("DB_MAX_CONNECTIONS")
+        // DB_POOL_SIZE, when set, takes precedence over DB_MAX_CONNECTIONS...
+        let db_max_connections: u32 = var("DB_POOL_SIZE")
+            .or_else(|_| var("DB_MAX_CONNECTIONS"))
             .unwrap_or("8".to_string())
             .parse()
-            .expect("Failed parsing DB_MAX_CONNECTIONS");
+            .expect("Failed parsing DB_POOL_SIZE | DB_MAX_CONNECTIONS");
+        assert!(
+            db_max_connections > 0,
+            "DB_POOL_SIZE | DB_MAX_CONNECTIONS must be greater than 0"
+        );
         let db_min_connections: u32 = var("DB_MIN_CONNECTIONS")
             .unwrap_or("4".to_string())
             .parse()
             .expect("Failed parsing DB_MIN_CONNECTIONS");
+        assert!(
+            db_min_connections <= db_max_connections,
+            "DB_MIN_CONNECTIONS must not exceed DB_POOL_SIZE | DB_MAX_CONNECTIONS"
+        );
         let db_acquire_timeout = Duration::from_secs(
             var("DB_ACQUIRE_TIMEOUT_SECS")
                 .unwrap_or("8".to_string())
@@ -118,6 +205,24 @@ impl Default for Config {
             db_statements_page_len > 0,
             "DB_STATEMENTS_PAGE_LEN must be greater than 0"
         );
+        let db_max_statements_page_len: i32 = var("DB_MAX_STATEMENTS_PAGE_LEN")
+            .unwrap_or(DEFAULT_DB_MAX_STATEMENTS_PAGE_LEN.to_string())
+            .parse()
+            .expect("Failed parsing DB_MAX_STATEMENTS_PAGE_LEN");
+        assert!(
+            db_max_statements_page_len >= db_statements_page_len,
+            "DB_MAX_STATEMENTS_PAGE_LEN must not be less than DB_STATEMENTS_PAGE_LEN"
+        );
+        let insert_batch_size: usize = var("INSERT_BATCH_SIZE")
+            .unwrap_or("50".to_string())
+            .parse()
+            .expect("Failed parsing INSERT_BATCH_SIZE");
+        // a Statement row takes 11 bind parameters + PostgreSQL allows at
+        // most 65,535 per query...
+        assert!(
+            (1..=5_000).contains(&insert_batch_size),
+            "INSERT_BATCH_SIZE must be w/in [1..5000]"
+        );
 
         let mut external_url = var("LRS_EXTERNAL_URL").expect("Missing LRS_EXTERNAL_URL");
         if external_url.ends_with(path::MAIN_SEPARATOR) {
@@ -168,6 +273,13 @@ impl Default for Config {
                 .expect("Failed parsing LRS_USER_CACHE_LEN"),
         )
         .expect("Failed converting LRS_USER_CACHE_LEN to unsigned integer");
+        let verb_cache_len = NonZeroUsize::new(
+            var("LRS_VERB_CACHE_LEN")
+                .unwrap_or("1000".to_string())
+                .parse()
+                .expect("Failed parsing LRS_VERB_CACHE_LEN"),
+        )
+        .expect("Failed converting LRS_VERB_CACHE_LEN to unsigned integer");
         // notify sysadmin of LRS_AUTHORITY_IFI's deprecation...
         if let Ok(x) = var("LRS_AUTHORITY_IFI") {
             if x != root_email {
@@ -209,11 +321,74 @@ impl Default for Config {
         // ensure it's valid...
         let _ = MyLanguageTag::from_str(&default_language).expect("Invalid default language tag");
 
+        let max_name_length: usize = var("MAX_NAME_LENGTH")
+            .unwrap_or(DEFAULT_MAX_NAME_LENGTH.to_string())
+            .parse()
+            .expect("Failed parsing MAX_NAME_LENGTH");
+        assert!(
+            max_name_length > 0,
+            "MAX_NAME_LENGTH must be greater than 0"
+        );
+
+        let max_json_depth: usize = var("MAX_JSON_DEPTH")
+            .unwrap_or(DEFAULT_MAX_JSON_DEPTH.to_string())
+            .parse()
+            .expect("Failed parsing MAX_JSON_DEPTH");
+        assert!(max_json_depth > 0, "MAX_JSON_DEPTH must be greater than 0");
+
         let jws_strict: bool = var("JWS_STRICT")
             .unwrap_or("false".to_owned())
             .parse()
             .expect("Failed parsing JWS_STRICT");
 
+        let https_file_urls: bool = var("HTTPS_FILE_URLS")
+            .unwrap_or("false".to_owned())
+            .parse()
+            .expect("Failed parsing HTTPS_FILE_URLS");
+
+        let metrics_enabled: bool = var("METRICS_ENABLED")
+            .unwrap_or("false".to_owned())
+            .parse()
+            .expect("Failed parsing METRICS_ENABLED");
+
+        let resumable_uploads: bool = var("RESUMABLE_UPLOADS")
+            .unwrap_or("false".to_owned())
+            .parse()
+            .expect("Failed parsing RESUMABLE_UPLOADS");
+
+        let upload_ttl: u64 = var("UPLOAD_TTL_SECS")
+            .unwrap_or(DEFAULT_UPLOAD_TTL_SECS.to_string())
+            .parse()
+            .expect("Failed parsing UPLOAD_TTL_SECS");
+
+        let doc_cache_control = var("DOC_CACHE_CONTROL").unwrap_or("no-cache".to_owned());
+
+        let reject_client_stored: bool = var("REJECT_CLIENT_STORED")
+            .unwrap_or("false".to_owned())
+            .parse()
+            .expect("Failed parsing REJECT_CLIENT_STORED");
+
+        let json_limit = body_limit("JSON_LIMIT", "json", "2 MiB");
+
+        let multipart_limit = body_limit("MULTIPART_LIMIT", "file/multipart", "10 MiB");
+
+        let response_time_header: bool = var("RESPONSE_TIME_HEADER")
+            .unwrap_or("false".to_owned())
+            .parse()
+            .expect("Failed parsing RESPONSE_TIME_HEADER");
+
+        let reject_duplicate_keys: bool = var("REJECT_DUPLICATE_KEYS")
+            .unwrap_or("false".to_owned())
+            .parse()
+            .expect("Failed parsing REJECT_DUPLICATE_KEYS");
+
+        let extensions_allowlist: Vec<IriString> = var("EXTENSIONS_ALLOWLIST")
+            .unwrap_or_default()
+            .split([',', ' '])
+            .filter(|x| !x.is_empty())
+            .map(|x| IriString::try_from(x).expect("Invalid IRI in EXTENSIONS_ALLOWLIST"))
+            .collect();
+
         Self {
             db_server_url,
             db_name,
@@ -223,18 +398,34 @@ impl Default for Config {
             db_idle_timeout,
             db_max_lifetime,
             db_statements_page_len,
+            db_max_statements_page_len,
+            insert_batch_size,
             external_url,
             static_dir,
             mode,
             root_email,
             root_credentials,
             user_cache_len,
+            verb_cache_len,
             ttl_batch_len,
             ttl,
             ttl_interval,
             mfc_interval,
             default_language,
+            max_name_length,
+            max_json_depth,
             jws_strict,
+            https_file_urls,
+            metrics_enabled,
+            resumable_uploads,
+            upload_ttl,
+            doc_cache_control,
+            reject_client_stored,
+            json_limit,
+            multipart_limit,
+            response_time_header,
+            reject_duplicate_keys,
+            extensions_allowlist,
         }
     }
 }
@@ -256,6 +447,18 @@ impl Config {
     }
 }
 
+/// Return the request body limit set by the environment variable `key`. When
+/// not set, fall back to the `limit` one in Rocket.toml (or a `ROCKET_LIMITS`
+/// environment variable) if any; and to `default` otherwise.
+fn body_limit(key: &str, limit: &str, default: &str) -> ByteUnit {
+    match var(key) {
+        Ok(x) => x.parse().unwrap_or_else(|_| panic!("Failed parsing {key}")),
+        Err(_) => rocket::Config::figment()
+            .extract_inner::<ByteUnit>(&format!("limits.{limit}"))
+            .unwrap_or_else(|_| default.parse().expect("Failed parsing default limit")),
+    }
+}
+
 fn my_home_dir() -> String {
     let mut result = var("CARGO_MANIFEST_DIR").expect("Failed accessing Cargo vars...");
     if result.ends_with(path::MAIN_SEPARATOR) {
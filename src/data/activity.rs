@@ -10,13 +10,13 @@ use crate::{
 };
 use core::fmt;
 use iri_string::types::{IriStr, IriString};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 use std::{
     hash::{Hash, Hasher},
-    mem,
     str::FromStr,
+    sync::Arc,
 };
 
 /// Structure making up "this" in "I did this"; it is something with which an
@@ -37,7 +37,9 @@ pub struct Activity {
     #[serde(rename = "objectType")]
     object_type: Option<ObjectType>,
     id: IriString,
-    definition: Option<ActivityDefinition>,
+    // NOTE (rsn) 20261016 - definitions can be large and are often shared by
+    // the Activities referenced in a page of Statements; hence the `Arc`...
+    definition: Option<Arc<ActivityDefinition>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -85,7 +87,7 @@ impl Activity {
 
     /// Return `definition` field if set; `None` otherwise.
     pub fn definition(&self) -> Option<&ActivityDefinition> {
-        self.definition.as_ref()
+        self.definition.as_deref()
     }
 
     /// Consumes `other`'s `definition` replacing or augmenting `self`'s.
@@ -93,19 +95,12 @@ impl Activity {
         // FIXME (rsn) 20250412 - change the signature to return a Result
         // raising an error if both arguments do not share the same ID instead
         // of silently returning...
-        if self.id == other.id {
-            if self.definition.is_none() {
-                if let Some(mut z_other_definition) = other.definition {
-                    let x = mem::take(&mut z_other_definition);
-                    let mut z = Some(x);
-                    mem::swap(&mut self.definition, &mut z);
-                }
-            } else if let Some(y) = other.definition {
-                let mut x = mem::take(&mut self.definition).unwrap();
-                // let y = other.definition.unwrap();
-                x.merge(y);
-                let mut z = Some(x);
-                mem::swap(&mut self.definition, &mut z);
+        if self.id == other.id
+            && let Some(y) = other.definition
+        {
+            match self.definition.as_mut() {
+                None => self.definition = Some(y),
+                Some(x) => Arc::make_mut(x).merge(Arc::unwrap_or_clone(y)),
             }
         }
     }
@@ -279,6 +274,19 @@ impl Activity {
         }
     }
 
+    /// Convenience pass-through method to the `definition` field.
+    /// Return extension keyed by `key`, deserialized as a `T`, if it exists;
+    /// `None` otherwise. See [Extensions::get_typed].
+    pub fn extension_typed<T: DeserializeOwned>(
+        &self,
+        key: &IriStr,
+    ) -> Result<Option<T>, DataError> {
+        match self.extensions() {
+            None => Ok(None),
+            Some(x) => x.get_typed(key),
+        }
+    }
+
     /// Ensure `object_type` field is set.
     pub fn set_object_type(&mut self) {
         self.object_type = Some(ObjectType::Activity);
@@ -337,7 +345,7 @@ impl Validate for Activity {
 impl Canonical for Activity {
     fn canonicalize(&mut self, language_tags: &[MyLanguageTag]) {
         if let Some(z_definition) = &mut self.definition {
-            z_definition.canonicalize(language_tags);
+            Arc::make_mut(z_definition).canonicalize(language_tags);
         }
     }
 }
@@ -357,7 +365,7 @@ impl FromStr for Activity {
 pub struct ActivityBuilder<'a> {
     _object_type: Option<ObjectType>,
     _id: Option<&'a IriStr>,
-    _definition: Option<ActivityDefinition>,
+    _definition: Option<Arc<ActivityDefinition>>,
 }
 
 impl<'a> ActivityBuilder<'a> {
@@ -390,20 +398,23 @@ impl<'a> ActivityBuilder<'a> {
     /// Raise [DataError] if the argument is invalid.
     pub fn definition(mut self, val: ActivityDefinition) -> Result<Self, DataError> {
         val.check_validity()?;
-        self._definition = Some(val);
+        self._definition = Some(Arc::new(val));
         Ok(self)
     }
 
+    /// Set the `definition` field to one shared w/ other instances. It's
+    /// assumed to have already been validated.
+    pub(crate) fn shared_definition(mut self, val: Arc<ActivityDefinition>) -> Self {
+        self._definition = Some(val);
+        self
+    }
+
     /// Merge given definition w/ this one.
     pub fn add_definition(mut self, val: ActivityDefinition) -> Result<Self, DataError> {
         val.check_validity()?;
-        if self._definition.is_none() {
-            self._definition = Some(val)
-        } else {
-            let mut x = mem::take(&mut self._definition).unwrap();
-            x.merge(val);
-            let mut z = Some(x);
-            mem::swap(&mut self._definition, &mut z);
+        match self._definition.as_mut() {
+            None => self._definition = Some(Arc::new(val)),
+            Some(x) => Arc::make_mut(x).merge(val),
         }
         Ok(self)
     }
@@ -663,4 +674,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[traced_test]
+    #[test]
+    fn test_extension_typed() -> Result<(), DataError> {
+        const ROOM_KEY: &str =
+            "http://example.com/profiles/meetings/activitydefinitionextensions/room";
+        const JSON: &str = r#"{
+            "id": "http://www.example.com/meetings/occurances/34534",
+            "definition": {
+                "extensions": {
+                    "http://example.com/profiles/meetings/activitydefinitionextensions/room": {
+                        "name": "Kilby",
+                        "id": "http://example.com/rooms/342"
+                    }
+                }
+            }
+        }"#;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Room {
+            name: String,
+            id: String,
+        }
+
+        let act = Activity::from_str(JSON)?;
+        let key = IriStr::new(ROOM_KEY)?;
+        let room: Option<Room> = act.extension_typed(key)?;
+        assert_eq!(
+            room,
+            Some(Room {
+                name: "Kilby".to_owned(),
+                id: "http://example.com/rooms/342".to_owned()
+            })
+        );
+        // a mismatched type is an error...
+        assert!(act.extension_typed::<u32>(key).is_err());
+        // an Activity w/o a definition has no extensions...
+        let bare = Activity::from_str(r#"{"id":"http://www.example.com/bare"}"#)?;
+        assert!(bare.extension_typed::<Room>(key)?.is_none());
+
+        Ok(())
+    }
 }
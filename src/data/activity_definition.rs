@@ -53,6 +53,11 @@ impl ActivityDefinition {
         }
     }
 
+    /// Return the `name` Language Map if set; `None` otherwise.
+    pub(crate) fn name_as_map(&self) -> Option<&LanguageMap> {
+        self.name.as_ref()
+    }
+
     /// Return the `description` for the given language `tag` if it exists;
     /// `None` otherwise.
     pub fn description(&self, tag: &MyLanguageTag) -> Option<&str> {
@@ -281,7 +286,9 @@ impl Validate for ActivityDefinition {
         if self.type_.is_some() && self.type_.as_ref().unwrap().is_empty() {
             vec.push(ValidationError::InvalidIRI("type".into()))
         }
-        // validate more_info
+        // validate more_info.  NOTE (rsn) 20261016 - relative references are
+        // rejected when deserializing an `IriString`; here we ensure it's also
+        // a well-formed (absolute) IRL...
         if let Some(z_more_info) = self.more_info.as_ref() {
             validate_irl(z_more_info).unwrap_or_else(|x| vec.push(x));
         }
@@ -300,6 +307,11 @@ impl Validate for ActivityDefinition {
         }
         // validate correct response pattern
         if let Some(z_correct_responses_pattern) = self.correct_responses_pattern.as_ref() {
+            // present but empty is more likely a mistake than a deliberate
+            // "no correct answer"...
+            if z_correct_responses_pattern.is_empty() {
+                vec.push(ValidationError::Empty("correctResponsesPattern".into()))
+            }
             for it in z_correct_responses_pattern.iter() {
                 if it.is_empty() {
                     vec.push(ValidationError::Empty("correctResponsePattern".into()))
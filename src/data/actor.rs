@@ -79,6 +79,20 @@ impl Actor {
         }
     }
 
+    /// Construct and validate an [Actor] from a JSON value which must be an
+    /// Object. Dispatch on its `objectType`, assuming [Agent] if missing.
+    ///
+    /// Raise [DataError] if `value` is not an Object or if the resulting
+    /// [Agent] or [Group] is invalid.
+    pub fn from_json(value: &Value) -> Result<Self, DataError> {
+        match value.as_object() {
+            Some(map) => Self::from_json_obj(map.to_owned()),
+            None => emit_error!(DataError::Validation(ValidationError::ConstraintViolation(
+                format!("Actor must be a JSON Object ({value})").into()
+            ))),
+        }
+    }
+
     /// Coerce an [Agent] to an [Actor].
     pub fn from_agent(actor: Agent) -> Self {
         Actor::Agent(actor)
@@ -314,4 +328,33 @@ mod tests {
         assert!(r2.is_err()); // unknown field
         assert!(r2.err().unwrap().is_data());
     }
+
+    #[traced_test]
+    #[test]
+    fn test_from_json() -> Result<(), DataError> {
+        let v = serde_json::json!({"mbox": "mailto:zuser@somewhere.net"});
+        let actor = Actor::from_json(&v)?;
+        assert!(actor.is_agent());
+
+        let v = serde_json::json!({
+            "objectType": "Group",
+            "name": "Z Team",
+            "member": [{"mbox": "mailto:zuser@somewhere.net"}]
+        });
+        let actor = Actor::from_json(&v)?;
+        assert!(actor.is_group());
+        assert_eq!(actor.as_group()?.members().len(), 1);
+
+        // not an Object...
+        assert!(Actor::from_json(&serde_json::json!("mailto:zuser@somewhere.net")).is_err());
+        // unknown objectType...
+        assert!(Actor::from_json(&serde_json::json!({"objectType": "Foo"})).is_err());
+        // an Agent w/o an IFI...
+        assert!(Actor::from_json(&serde_json::json!({"name": "Z User"})).is_err());
+        // null values are not allowed...
+        let v = serde_json::json!({"mbox": "mailto:zuser@somewhere.net", "name": null});
+        assert!(Actor::from_json(&v).is_err());
+
+        Ok(())
+    }
 }
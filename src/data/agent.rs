@@ -75,7 +75,7 @@ impl Agent {
                     format!("Key '{k}' is null").into()
                 )))
             } else {
-                check_for_nulls(v)?
+                check_for_nulls(v, usize::MAX)?
             }
         }
         // finally convert it to an agent...
@@ -14,7 +14,6 @@ use mime::Mime;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none};
 use std::str::FromStr;
-use tracing::warn;
 
 /// Mandated 'usageTpe' to use when an [Attachment] is a JWS signature.
 pub const SIGNATURE_UT: &str = "http://adlnet.gov/expapi/attachments/signature";
@@ -110,6 +109,20 @@ impl Attachment {
         self.file_url = Some(IriString::from_str(url).unwrap());
     }
 
+    /// Raise [ValidationError] if `file_url` is set and its scheme is not
+    /// `https`. Used when a deployment requires attachment contents to only
+    /// be fetched over secure connections.
+    pub fn validate_https(&self) -> Result<(), ValidationError> {
+        match self.file_url.as_ref() {
+            Some(x) if !x.scheme_str().eq_ignore_ascii_case("https") => {
+                Err(ValidationError::ConstraintViolation(
+                    format!("Attachment 'fileUrl' ({x}) must use HTTPS").into(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Return TRUE if this is a JWS signature; FALSE otherwise.
     pub fn is_signature(&self) -> bool {
         // an Attachment is considered a potential JWS Signature iff its
@@ -149,7 +162,7 @@ impl Validate for Attachment {
         let mut vec = vec![];
 
         if self.display.is_empty() {
-            warn!("Attachment display dictionary is empty")
+            vec.push(ValidationError::Empty("display".into()))
         }
         if self.content_type.type_().as_str().is_empty() {
             vec.push(ValidationError::Empty("content_type".into()))
@@ -405,6 +418,35 @@ mod tests {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_validate_https() -> Result<(), DataError> {
+        const JSON: &str = r#"
+        {
+            "usageType": "http://example.com/attachment-usage/test",
+            "display": { "en-US": "A test attachment" },
+            "contentType": "text/plain",
+            "length": 27,
+            "sha2": "495395e777cd98da653df9615d09c0fd6bb2f8d4788394cd53c56a3bfdcd848a",
+            "fileUrl": "FILE_URL"
+        }"#;
+
+        let att: Attachment = serde_json::from_str(&JSON.replace("FILE_URL", "http://x.net/a"))?;
+        assert!(att.validate().is_empty());
+        assert!(att.validate_https().is_err());
+
+        let att: Attachment = serde_json::from_str(&JSON.replace("FILE_URL", "https://x.net/a"))?;
+        assert!(att.validate().is_empty());
+        assert!(att.validate_https().is_ok());
+
+        // w/o a `fileUrl` there's nothing to reject...
+        let mut att = att;
+        att.file_url = None;
+        assert!(att.validate_https().is_ok());
+
+        Ok(())
+    }
+
     #[traced_test]
     #[test]
     fn test_builder() -> Result<(), DataError> {
@@ -279,6 +279,20 @@ impl fmt::Display for Context {
 }
 
 impl Validate for Context {
+    /// Besides validating each of its properties, the rules governing a
+    /// [Context] w.r.t. the rest of its Statement, or SubStatement, are...
+    ///
+    /// * `registration` when present is neither the nil nor the max UUID.
+    ///   A malformed one fails deserialization.
+    /// * `instructor` is an Agent or a Group, and `team` is a Group. Their
+    ///   types only constrain their shape when deserializing; the `objectType`
+    ///   of each is checked here, reporting which property is wrong.
+    /// * `revision` and `platform` are only used when the `object` is an
+    ///   Activity. We don't have access to it here, so this is checked by the
+    ///   containing Statement or SubStatement.
+    /// * `instructor` and `team` are described as used "if not included as
+    ///   the Actor". This is not enforced; the xAPI long example Statement
+    ///   uses its `actor` Group as its `team`.
     fn validate(&self) -> Vec<ValidationError> {
         let mut vec = vec![];
 
@@ -291,9 +305,23 @@ impl Validate for Context {
             vec.push(ValidationError::ConstraintViolation(msg.into()))
         }
         if let Some(z_instructor) = self.instructor.as_ref() {
+            let ok = match z_instructor {
+                Actor::Agent(x) => x.check_object_type(),
+                Actor::Group(x) => x.check_object_type(),
+            };
+            if !ok {
+                let msg = "Context 'instructor' must be an Agent or a Group";
+                error!("{}", msg);
+                vec.push(ValidationError::ConstraintViolation(msg.into()))
+            }
             vec.extend(z_instructor.validate())
         }
         if let Some(z_team) = self.team.as_ref() {
+            if !z_team.check_object_type() {
+                let msg = "Context 'team' must be a Group";
+                error!("{}", msg);
+                vec.push(ValidationError::ConstraintViolation(msg.into()))
+            }
             vec.extend(z_team.validate());
         }
         if let Some(z_activities) = self.context_activities.as_ref() {
@@ -315,6 +343,16 @@ impl Validate for Context {
         if self.platform.is_some() && self.platform.as_ref().unwrap().is_empty() {
             vec.push(ValidationError::Empty("platform".into()))
         }
+        // NOTE (rsn) 20261016 - malformed tags are rejected when deserializing;
+        // well-formed ones w/ unregistered sub-tags are caught here...
+        if let Some(z_language) = self.language.as_ref()
+            && let Err(x) = z_language.validate()
+        {
+            error!("{}", x);
+            vec.push(ValidationError::InvalidLanguageTag(
+                z_language.to_string().into(),
+            ))
+        }
         if let Some(z_statement) = self.statement.as_ref() {
             vec.extend(z_statement.validate())
         }
@@ -611,4 +649,44 @@ mod tests {
         assert!(de_result.is_ok());
         let _ctx = de_result.unwrap();
     }
+
+    #[traced_test]
+    #[test]
+    fn test_invalid_language() {
+        const JSON1: &str = r#"{"language":"not a tag!!"}"#;
+        assert!(serde_json::from_str::<Context>(JSON1).is_err());
+
+        const JSON2: &str = r#"{"language":"xx"}"#;
+        let ctx = serde_json::from_str::<Context>(JSON2).unwrap();
+        assert!(!ctx.is_valid());
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_instructor_object_type() {
+        const JSON: &str = r#"{"instructor":{"objectType":"TYPE","mbox":"mailto:i@example.com"}}"#;
+
+        let names_it = |x: &ValidationError| x.to_string().contains("'instructor'");
+
+        for t in ["Agent", "Group"] {
+            let ctx = serde_json::from_str::<Context>(&JSON.replace("TYPE", t)).unwrap();
+            assert!(!ctx.validate().iter().any(names_it));
+        }
+        // deserializes as a Group w/ the wrong objectType...
+        let ctx = serde_json::from_str::<Context>(&JSON.replace("TYPE", "Activity")).unwrap();
+        assert!(ctx.validate().iter().any(names_it));
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_team_object_type() {
+        const JSON: &str = r#"{"team":{"objectType":"TYPE","mbox":"mailto:t@example.com"}}"#;
+
+        let names_it = |x: &ValidationError| x.to_string().contains("'team'");
+
+        let ctx = serde_json::from_str::<Context>(&JSON.replace("TYPE", "Group")).unwrap();
+        assert!(ctx.is_valid());
+        let ctx = serde_json::from_str::<Context>(&JSON.replace("TYPE", "Agent")).unwrap();
+        assert!(ctx.validate().iter().any(names_it));
+    }
 }
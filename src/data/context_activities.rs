@@ -355,4 +355,19 @@ mod tests {
         let actual = serde_json::to_string(&ca).unwrap();
         assert_eq!(EXPECTED, actual);
     }
+
+    #[test]
+    fn test_single_category_as_array() {
+        const CA: &str = r#"{"category":{"id":"http://xapi.activity/cat"}}"#;
+        const EXPECTED: &str = r#"{"category":[{"id":"http://xapi.activity/cat"}]}"#;
+
+        let ca = serde_json::from_str::<ContextActivities>(CA).unwrap();
+        assert_eq!(ca.category().len(), 1);
+        let actual = serde_json::to_string(&ca).unwrap();
+        assert_eq!(EXPECTED, actual);
+
+        // same when only IDs are to be emitted...
+        let actual = serde_json::to_string(&ContextActivitiesId::from(ca)).unwrap();
+        assert_eq!(EXPECTED, actual);
+    }
 }
@@ -80,6 +80,35 @@ impl MyDuration {
         self.0.microsecond
     }
 
+    /// Return the signed total number of seconds, incl. fractions, in this.
+    pub fn total_seconds(&self) -> f64 {
+        self.0.signed_total_seconds() as f64 + self.0.signed_microseconds() as f64 / 1_000_000.0
+    }
+
+    /// Return a new instance representing the sum of this and `other`.
+    ///
+    /// Raise [DataError] if the result is out of range.
+    pub fn add(&self, other: &MyDuration) -> Result<Self, DataError> {
+        const MICROS_PER_SEC: i128 = 1_000_000;
+        const MICROS_PER_DAY: i128 = 86_400 * MICROS_PER_SEC;
+
+        let micros = |x: &Duration| {
+            x.signed_total_seconds() as i128 * MICROS_PER_SEC + x.signed_microseconds() as i128
+        };
+        let total = micros(&self.0) + micros(&other.0);
+        let positive = total >= 0;
+        let total = total.abs();
+        let day = u32::try_from(total / MICROS_PER_DAY).map_err(|x| {
+            error!("{}", x);
+            DataError::Duration(format!("Sum of {self} and {other} is too large").into())
+        })?;
+        let rest = total % MICROS_PER_DAY;
+        // both are guaranteed to fit...
+        let second = (rest / MICROS_PER_SEC) as u32;
+        let microsecond = (rest % MICROS_PER_SEC) as u32;
+        MyDuration::new(positive, day, second, microsecond)
+    }
+
     /// Return this in ISO8601 format; i.e. "P9DT9H9M9.99S"
     pub fn to_iso8601(&self) -> String {
         let inner = &self.0;
@@ -189,6 +218,30 @@ mod tests {
         assert_eq!(d1.microsecond() / 10_000, d2.microsecond() / 10_000);
     }
 
+    #[test]
+    fn test_add() -> Result<(), DataError> {
+        let half_hour = MyDuration::from_str("PT30M")?;
+        let sum = half_hour.add(&half_hour)?;
+        assert_eq!(sum, MyDuration::from_str("PT1H")?);
+        assert_eq!(sum.total_seconds(), 3600.0);
+
+        let d1 = MyDuration::from_str("PT23H59M59.5S")?;
+        let d2 = MyDuration::from_str("PT0.75S")?;
+        let sum = d1.add(&d2)?;
+        assert_eq!(sum.day(), 1);
+        assert_eq!(sum.second(), 0);
+        assert_eq!(sum.microsecond(), 250_000);
+        assert_eq!(sum.total_seconds(), 86_400.25);
+
+        let minus = MyDuration::new(false, 0, 60, 0)?;
+        let sum = half_hour.add(&minus)?;
+        assert!(sum.positive());
+        assert_eq!(sum.total_seconds(), 1740.0);
+        assert_eq!(minus.total_seconds(), -60.0);
+
+        Ok(())
+    }
+
     #[test]
     #[should_panic]
     fn test_deserialization() {
@@ -3,6 +3,7 @@
 use crate::data::{DataError, Fingerprint};
 use core::fmt;
 use email_address::EmailAddress;
+use openssl::sha::sha1;
 use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
@@ -31,6 +32,13 @@ impl MyEmailAddress {
     pub fn to_uri(&self) -> String {
         self.0.to_uri()
     }
+
+    /// Return the hex-encoded SHA1 hash of this email address formatted as a
+    /// URI; i.e. the value of an `mbox_sha1sum` property identifying the same
+    /// entity as an `mbox` one w/ this address.
+    pub fn to_sha1sum(&self) -> String {
+        hex::encode(sha1(self.to_uri().as_bytes()))
+    }
 }
 
 impl FromStr for MyEmailAddress {
@@ -154,6 +162,15 @@ mod tests {
         assert!(r5.is_err());
     }
 
+    #[test]
+    fn test_to_sha1sum() {
+        let em = MyEmailAddress::from_str("xapi@adlnet.gov").unwrap();
+        assert_eq!(em.to_sha1sum(), "cd9b00a5611f94eaa7b1661edab976068e364975");
+        // w/ or w/o the scheme, the hash is the same...
+        let em = MyEmailAddress::from_str("mailto:xapi@adlnet.gov").unwrap();
+        assert_eq!(em.to_sha1sum(), "cd9b00a5611f94eaa7b1661edab976068e364975");
+    }
+
     #[test]
     fn test_email_eq() {
         let em1 = EmailAddress::from_str("me@gmailbox.net");
@@ -1,9 +1,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::data::{DataError, Fingerprint};
+use crate::data::{DataError, Fingerprint, ValidationError};
 use core::fmt;
 use iri_string::types::{IriStr, IriString};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use std::{
     collections::BTreeMap,
@@ -52,6 +52,18 @@ impl Extensions {
         self.0.get(key)
     }
 
+    /// Return the [Value] associated w/ the given `key`, deserialized as a
+    /// `T`, if present in this collection; `None` otherwise.
+    ///
+    /// Raise a [DataError] if the value exists but can't be deserialized
+    /// into a `T`.
+    pub fn get_typed<T: DeserializeOwned>(&self, key: &IriStr) -> Result<Option<T>, DataError> {
+        match self.0.get(key) {
+            None => Ok(None),
+            Some(x) => Ok(Some(serde_json::from_value(x.to_owned())?)),
+        }
+    }
+
     /// Return the number of entries in the collection.
     pub fn len(&self) -> usize {
         self.0.len()
@@ -70,6 +82,20 @@ impl Extensions {
         Ok(())
     }
 
+    /// Return a potentially empty collection of [ValidationError]s, one for
+    /// every key in this collection that is not in `allowed`.
+    pub fn validate_allowed(&self, allowed: &[IriString]) -> Vec<ValidationError> {
+        self.0
+            .keys()
+            .filter(|k| !allowed.contains(k))
+            .map(|k| {
+                ValidationError::ConstraintViolation(
+                    format!("Extension <{k}> is not allowed").into(),
+                )
+            })
+            .collect()
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
     ///
     /// If a key from `other` is already present in `self`, the respective
@@ -131,4 +157,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_validate_allowed() -> Result<(), DataError> {
+        const OK: &str = "http://www.nowhere.net/ok";
+        const KO: &str = "http://www.nowhere.net/ko";
+
+        let mut ext = Extensions::new();
+        ext.add(OK, &serde_json::json!(1))?;
+        ext.add(KO, &serde_json::json!(2))?;
+
+        let allowed = vec![IriString::try_from(OK).unwrap()];
+        let errors = ext.validate_allowed(&allowed);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains(KO));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_typed() -> Result<(), DataError> {
+        const IRI: &str = "http://www.nowhere.net/score";
+
+        let mut ext = Extensions::new();
+        ext.add(IRI, &serde_json::json!(42))?;
+
+        let iri = IriStr::new(IRI).unwrap();
+        assert_eq!(ext.get_typed::<u32>(iri)?, Some(42));
+        // wrong type...
+        assert!(ext.get_typed::<String>(iri).is_err());
+        // missing key...
+        let other = IriStr::new("http://www.nowhere.net/other").unwrap();
+        assert_eq!(ext.get_typed::<u32>(other)?, None);
+
+        Ok(())
+    }
 }
@@ -109,7 +109,7 @@ impl Group {
                     format!("Key '{k}' is null").into()
                 )))
             } else {
-                check_for_nulls(v)?
+                check_for_nulls(v, usize::MAX)?
             }
         }
         // finally convert it to a group...
@@ -300,8 +300,9 @@ impl Validate for Group {
             vec.extend(z_account.validate())
         }
         if self.is_anonymous() {
-            // must contain at least 1 member...
-            if self.members.is_none() {
+            // must contain at least 1 member; i.e. `member` is neither missing
+            // nor an empty array...
+            if self.members.as_ref().is_none_or(|x| x.is_empty()) {
                 vec.push(ValidationError::EmptyAnonymousGroup)
             }
         } else if count != 1 {
@@ -552,6 +553,28 @@ mod tests {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_anonymous_group_w_0_members() {
+        const G1: &str = r#"{"objectType":"Group","name":"Group Anonymous"}"#;
+        const G2: &str = r#"{"objectType":"Group","name":"Group Anonymous","member":[]}"#;
+
+        for json in [G1, G2] {
+            let g = serde_json::from_str::<Group>(json).unwrap();
+            assert!(g.is_anonymous());
+            let violations = g.validate();
+            assert_eq!(violations.len(), 1);
+            assert!(matches!(
+                violations[0],
+                ValidationError::EmptyAnonymousGroup
+            ));
+            assert_eq!(
+                violations[0].to_string(),
+                "Anonymous Group must have at least 1 member"
+            );
+        }
+    }
+
     #[traced_test]
     #[test]
     fn test_long_group() {
@@ -4,7 +4,7 @@ use crate::data::{Canonical, DataError, MultiLingual, MyLanguageTag};
 use core::fmt;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{btree_map::Keys, BTreeMap},
+    collections::{btree_map::{Iter, Keys}, BTreeMap},
     mem,
 };
 
@@ -62,6 +62,11 @@ impl LanguageMap {
         self.0.keys()
     }
 
+    /// Return an iterator over this dictionary's entries sorted by key.
+    pub fn iter(&self) -> Iter<'_, MyLanguageTag, String> {
+        self.0.iter()
+    }
+
     /// Return TRUE if `k` is a known key of this dictionary; FALSE otherwise.
     pub fn contains_key(&self, k: &MyLanguageTag) -> bool {
         self.0.contains_key(k)
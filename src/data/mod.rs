@@ -40,6 +40,7 @@ pub(crate) mod statement_type;
 mod sub_statement;
 mod sub_statement_object;
 mod timestamp;
+mod unique_keys;
 mod validate;
 mod verb;
 mod version;
@@ -82,9 +83,11 @@ pub use statement_ids::*;
 pub use statement_object::*;
 pub use statement_ref::*;
 pub use statement_result::*;
+pub use statement_type::StatementView;
 pub use sub_statement::*;
 pub use sub_statement_object::*;
 pub use timestamp::MyTimestamp;
+pub(crate) use unique_keys::check_duplicate_keys;
 pub use validate::*;
 pub use verb::*;
 pub use version::*;
@@ -174,9 +177,14 @@ macro_rules! merge_maps {
     };
 }
 
-/// Recursively check if a JSON Object contains 'null' values.
-fn check_for_nulls(val: &Value) -> Result<(), ValidationError> {
+/// Recursively check if a JSON Object contains 'null' values, and that it's
+/// not nested more than `depth` levels deep. Values inside `extensions` may
+/// be 'null' but still count towards the depth.
+fn check_for_nulls(val: &Value, depth: usize) -> Result<(), ValidationError> {
     if let Some(obj) = val.as_object() {
+        if depth == 0 {
+            emit_error!(too_deep())
+        }
         // NOTE (rsn) 20241104 - from "4.2.1 Table Guidelines": "The LRS
         // shall reject Statements with any null values (except inside
         // extensions)."
@@ -186,13 +194,38 @@ fn check_for_nulls(val: &Value) -> Result<(), ValidationError> {
                     format!("Key '{k}' is 'null'").into()
                 ))
             } else if k != "extensions" {
-                check_for_nulls(v)?
+                check_for_nulls(v, depth - 1)?
+            } else {
+                check_depth(v, depth - 1)?
             }
         }
+    } else {
+        check_depth(val, depth)?
     }
     Ok(())
 }
 
+/// Recursively check that a JSON value is not nested more than `depth`
+/// levels deep.
+fn check_depth(val: &Value, depth: usize) -> Result<(), ValidationError> {
+    let children: Vec<&Value> = match val {
+        Value::Object(x) => x.values().collect(),
+        Value::Array(x) => x.iter().collect(),
+        _ => return Ok(()),
+    };
+    if depth == 0 {
+        emit_error!(too_deep())
+    }
+    for v in children {
+        check_depth(v, depth - 1)?
+    }
+    Ok(())
+}
+
+fn too_deep() -> ValidationError {
+    ValidationError::ConstraintViolation("JSON is nested too deeply".into())
+}
+
 /// A Serializer implementation that ensures `stored` timestamps show
 /// milli-second precision.
 fn stored_ser<S>(this: &Option<DateTime<Utc>>, ser: S) -> Result<S::Ok, S::Error>
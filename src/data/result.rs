@@ -74,6 +74,28 @@ impl XResult {
     pub fn extensions(&self) -> Option<&Extensions> {
         self.extensions.as_ref()
     }
+
+    /// Check the coherence of `success` w/ the `scaled` score if both are set
+    /// and return a potentially empty collection of [ValidationError]s. A
+    /// negative `scaled` score contradicts a `success` of TRUE, while a perfect
+    /// one (1.0) contradicts a `success` of FALSE.
+    ///
+    /// Only applied when a client asks for _strict_ handling of its request.
+    pub fn validate_strict(&self) -> Vec<ValidationError> {
+        let mut vec = vec![];
+
+        let scaled = self.score.as_ref().and_then(|x| x.scaled());
+        if let (Some(success), Some(scaled)) = (self.success, scaled)
+            && ((success && scaled < 0.0) || (!success && scaled == 1.0))
+        {
+            vec.push(ValidationError::ConstraintViolation(
+                format!("Result 'success' ({success}) contradicts 'scaled' score ({scaled})")
+                    .into(),
+            ))
+        }
+
+        vec
+    }
 }
 
 impl Fingerprint for XResult {
@@ -305,6 +327,26 @@ mod tests {
         Ok(())
     }
 
+    #[traced_test]
+    #[test]
+    fn test_strict_success_vs_scaled() {
+        const R1: &str = r#"{"success":true,"score":{"scaled":-0.5}}"#;
+        const R2: &str = r#"{"success":false,"score":{"scaled":1.0}}"#;
+        const R3: &str = r#"{"success":true,"score":{"scaled":0.5}}"#;
+
+        let r1 = serde_json::from_str::<XResult>(R1).unwrap();
+        // fine by default...
+        assert!(r1.validate().is_empty());
+        // ...but not when strict
+        assert_eq!(r1.validate_strict().len(), 1);
+
+        let r2 = serde_json::from_str::<XResult>(R2).unwrap();
+        assert_eq!(r2.validate_strict().len(), 1);
+
+        let r3 = serde_json::from_str::<XResult>(R3).unwrap();
+        assert!(r3.validate_strict().is_empty());
+    }
+
     #[traced_test]
     #[test]
     fn test_builder_w_duration() -> Result<(), DataError> {
@@ -2,14 +2,16 @@
 
 use crate::{
     data::{
-        Actor, ActorId, Attachment, Context, ContextId, DataError, Fingerprint, MyTimestamp,
-        MyVersion, StatementObject, StatementObjectId, Validate, ValidationError, Verb, VerbId,
-        XResult, check_for_nulls, fingerprint_it, statement_type::StatementType, stored_ser,
+        Activity, Actor, ActorId, Agent, Attachment, Context, ContextId, DataError, Extensions,
+        Fingerprint, Group, MyTimestamp, MyVersion, StatementObject, StatementObjectId,
+        SubStatementObject, Validate, ValidationError, Verb, VerbId, XResult, check_depth,
+        check_for_nulls, fingerprint_it, statement_type::StatementType, stored_ser, too_deep,
     },
     emit_error,
 };
-use chrono::{DateTime, SecondsFormat, Utc};
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
 use core::fmt;
+use iri_string::types::{IriStr, IriString};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use serde_with::skip_serializing_none;
@@ -40,11 +42,12 @@ pub struct Statement {
     attachments: Option<Vec<Attachment>>,
 }
 
-// a doppelgänger structure that abides by the 'ids' format rules.
+/// A doppelgänger of [Statement] that abides by the `ids` format rules; i.e.
+/// w/ Agents, Activities, Verbs and Groups reduced to their identifying
+/// properties.
 #[skip_serializing_none]
 #[derive(Debug, Serialize)]
-#[doc(hidden)]
-pub(crate) struct StatementId {
+pub struct StatementId {
     id: Option<Uuid>,
     actor: ActorId,
     verb: VerbId,
@@ -134,6 +137,20 @@ impl From<Box<StatementId>> for Statement {
 impl Statement {
     /// Construct and validate a [Statement] from a JSON map.
     pub fn from_json_obj(map: Map<String, Value>) -> Result<Self, DataError> {
+        Self::from_json_obj_bounded(map, usize::MAX)
+    }
+
+    /// Construct and validate a [Statement] from a JSON map w/ the added
+    /// constraint that it's not nested more than `max_depth` levels deep;
+    /// the map itself being the 1st level. This applies to `extensions`
+    /// values as well.
+    pub fn from_json_obj_bounded(
+        map: Map<String, Value>,
+        max_depth: usize,
+    ) -> Result<Self, DataError> {
+        if max_depth == 0 {
+            emit_error!(DataError::Validation(too_deep()))
+        }
         for (k, v) in &map {
             // NOTE (rsn) 20241104 - from "4.2.1 Table Guidelines": "The LRS
             // shall reject Statements with any null values (except inside
@@ -143,11 +160,18 @@ impl Statement {
                     format!("Key '{k}' is null").into()
                 )))
             } else if k != "extensions" {
-                check_for_nulls(v)?
+                check_for_nulls(v, max_depth - 1)?
+            } else {
+                check_depth(v, max_depth - 1)?
             }
         }
         // finally convert it to a Statement...
         let stmt: Statement = serde_json::from_value(Value::Object(map.to_owned()))?;
+        // report a misused `authority` explicitly rather than as a generic
+        // validation failure...
+        if let Some(z_authority) = stmt.authority.as_ref() {
+            check_authority(z_authority)?;
+        }
         stmt.check_validity()?;
         Ok(stmt)
     }
@@ -182,6 +206,12 @@ impl Statement {
         &self.verb
     }
 
+    /// Convenience pass-through method to the `verb` field.
+    /// Return the IRI identifying the _action_ taken by the _actor_.
+    pub fn verb_id(&self) -> &IriStr {
+        self.verb.id()
+    }
+
     /// Return TRUE if `verb` is _voided_; FALSE otherwise.
     pub fn is_verb_voided(&self) -> bool {
         self.verb.is_voided()
@@ -196,6 +226,19 @@ impl Statement {
         &self.object
     }
 
+    /// Return the IRI identifier of the `object` when it's an [Activity];
+    /// `None` otherwise.
+    ///
+    /// Note that a [StatementRef][crate::StatementRef] object is identified by
+    /// a UUID, not an IRI; use [voided_target][Self::voided_target] or the
+    /// [StatementRef][crate::StatementRef] itself for it.
+    pub fn object_iri(&self) -> Option<&IriStr> {
+        match &self.object {
+            StatementObject::Activity(x) => Some(x.id()),
+            _ => None,
+        }
+    }
+
     /// Return the UUID of the (target) Statement to be voided by this one iff
     /// (a) the verb is _voided_, and (b) the object is a [StatementRef][crate::StatementRef].
     ///
@@ -254,6 +297,10 @@ impl Statement {
         self.stored = Some(val);
     }
 
+    pub(crate) fn unset_stored(&mut self) {
+        self.stored = None;
+    }
+
     /// Return the [Agent][crate::Agent] or the [Group][crate::Group] who is
     /// asserting this [Statement] is TRUE if set or `None` otherwise.
     ///
@@ -319,10 +366,299 @@ impl Statement {
     pub fn equivalent(&self, that: &Statement) -> bool {
         self.uid() == that.uid()
     }
+
+    /// Apply the additional rules enforced when a client asks for _strict_
+    /// handling of its request and return a potentially empty collection of
+    /// [ValidationError]s. Those rules are:
+    ///
+    /// * `timestamp`, when set, must not be more than [MAX_FUTURE_SKEW_SECS]
+    ///   seconds ahead of the LRS clock.
+    /// * `extensions`, wherever they appear, must not be empty.
+    /// * An [Activity] w/ an `interactionType` must also have a
+    ///   `correctResponsesPattern`.
+    /// * A [Result][XResult]'s `success` must not contradict its `scaled`
+    ///   score. See [XResult::validate_strict].
+    pub fn validate_strict(&self) -> Vec<ValidationError> {
+        let mut rules = StrictRules(vec![]);
+
+        if let Some(z_timestamp) = self.timestamp() {
+            let cutoff = Utc::now() + TimeDelta::seconds(MAX_FUTURE_SKEW_SECS);
+            if *z_timestamp > cutoff {
+                rules.0.push(ValidationError::ConstraintViolation(
+                    format!(
+                        "'timestamp' ({}) is in the future",
+                        z_timestamp.to_rfc3339_opts(SecondsFormat::Millis, true)
+                    )
+                    .into(),
+                ))
+            }
+        }
+        self.visit(&mut rules);
+
+        rules.0
+    }
+
+    /// Return a potentially empty collection of [ValidationError]s for every
+    /// _name_ in this instance that is longer than `max` characters. Names
+    /// here are the `name` of [Agent]s and [Group]s (incl. members), and the
+    /// values of the `name` Language Map of [Activity] definitions.
+    pub fn check_name_lengths(&self, max: usize) -> Vec<ValidationError> {
+        let mut rule = NameLengths { max, vec: vec![] };
+        self.visit(&mut rule);
+        rule.vec
+    }
+
+    /// Return a potentially empty collection of [ValidationError]s for every
+    /// [Attachment], incl. those of a SubStatement object, whose `fileUrl`
+    /// does not use the HTTPS scheme.
+    pub fn check_file_urls(&self) -> Vec<ValidationError> {
+        let mut rule = FileUrls(vec![]);
+        self.visit(&mut rule);
+        rule.0
+    }
+
+    /// Return a potentially empty collection of [ValidationError]s for every
+    /// [Extensions] key, wherever it appears in this instance, that is not
+    /// in `allowed`.
+    pub fn check_extensions(&self, allowed: &[IriString]) -> Vec<ValidationError> {
+        let mut rule = AllowedExtensions {
+            allowed,
+            vec: vec![],
+        };
+        self.visit(&mut rule);
+        rule.vec
+    }
+
+    /// Walk this instance calling `visitor` for each of its parts, incl. those
+    /// of a [SubStatement][crate::SubStatement] object. The `authority` is not
+    /// visited.
+    pub(crate) fn visit(&self, visitor: &mut impl StatementVisitor) {
+        visit_actor(&self.actor, visitor);
+        match &self.object {
+            StatementObject::Agent(x) => visitor.agent(x),
+            StatementObject::Group(x) => visit_group(x, visitor),
+            StatementObject::Activity(x) => visitor.activity(x),
+            StatementObject::SubStatement(x) => {
+                visit_actor(x.actor(), visitor);
+                match x.object() {
+                    SubStatementObject::Agent(y) => visitor.agent(y),
+                    SubStatementObject::Group(y) => visit_group(y, visitor),
+                    SubStatementObject::Activity(y) => visitor.activity(y),
+                    SubStatementObject::StatementRef(_) => (),
+                }
+                visit_result_and_context(x.result(), x.context(), visitor);
+                for a in x.attachments().unwrap_or_default() {
+                    visitor.attachment(a)
+                }
+            }
+            StatementObject::StatementRef(_) => (),
+        }
+        visit_result_and_context(self.result(), self.context(), visitor);
+        for a in self.attachments() {
+            visitor.attachment(a)
+        }
+    }
+}
+
+/// Callbacks [Statement::visit] invokes for each part of a [Statement] a rule
+/// may need to check. A rule implements those it cares about; the others do
+/// nothing.
+pub(crate) trait StatementVisitor {
+    /// Called for every [Agent] incl. [Group] members.
+    fn agent(&mut self, _agent: &Agent) {}
+
+    /// Called for every [Group] before its members.
+    fn group(&mut self, _group: &Group) {}
+
+    /// Called for the [Activity] object and every Context Activity.
+    fn activity(&mut self, _activity: &Activity) {}
+
+    /// Called for every [Result][XResult].
+    fn result(&mut self, _result: &XResult) {}
+
+    /// Called for every [Context] before its Actors and Activities.
+    fn context(&mut self, _context: &Context) {}
+
+    /// Called for every [Attachment].
+    fn attachment(&mut self, _attachment: &Attachment) {}
+}
+
+fn visit_actor(actor: &Actor, visitor: &mut impl StatementVisitor) {
+    match actor {
+        Actor::Agent(x) => visitor.agent(x),
+        Actor::Group(x) => visit_group(x, visitor),
+    }
+}
+
+fn visit_group(group: &Group, visitor: &mut impl StatementVisitor) {
+    visitor.group(group);
+    for a in group.members() {
+        visitor.agent(a)
+    }
+}
+
+fn visit_result_and_context(
+    result: Option<&XResult>,
+    context: Option<&Context>,
+    visitor: &mut impl StatementVisitor,
+) {
+    if let Some(z_result) = result {
+        visitor.result(z_result)
+    }
+    if let Some(z_context) = context {
+        visitor.context(z_context);
+        if let Some(z_instructor) = z_context.instructor() {
+            visit_actor(z_instructor, visitor)
+        }
+        if let Some(z_team) = z_context.team() {
+            visit_group(z_team, visitor)
+        }
+        if let Some(z_activities) = z_context.context_activities() {
+            for a in z_activities
+                .parent()
+                .iter()
+                .chain(z_activities.grouping())
+                .chain(z_activities.category())
+                .chain(z_activities.other())
+            {
+                visitor.activity(a)
+            }
+        }
+    }
+}
+
+/// Maximum number of seconds a [Statement]'s `timestamp` can be ahead of the
+/// LRS clock when _strict_ handling is requested.
+pub const MAX_FUTURE_SKEW_SECS: i64 = 300;
+
+/// The rules of [Statement::validate_strict] other than the `timestamp` one.
+struct StrictRules(Vec<ValidationError>);
+
+impl StrictRules {
+    fn extensions(&mut self, name: &str, ext: Option<&Extensions>) {
+        if ext.is_some_and(|x| x.is_empty()) {
+            self.0.push(ValidationError::ConstraintViolation(
+                format!("{name} 'extensions' must not be empty").into(),
+            ))
+        }
+    }
+}
+
+impl StatementVisitor for StrictRules {
+    fn activity(&mut self, activity: &Activity) {
+        self.extensions("Activity definition", activity.extensions());
+        if activity.interaction_type().is_some() && activity.correct_responses_pattern().is_none() {
+            self.0.push(ValidationError::MissingField(
+                format!("correctResponsesPattern of interaction <{}>", activity.id()).into(),
+            ))
+        }
+    }
+
+    fn result(&mut self, result: &XResult) {
+        self.extensions("Result", result.extensions());
+        self.0.extend(result.validate_strict())
+    }
+
+    fn context(&mut self, context: &Context) {
+        self.extensions("Context", context.extensions())
+    }
+}
+
+/// The rule of [Statement::check_name_lengths].
+struct NameLengths {
+    max: usize,
+    vec: Vec<ValidationError>,
+}
+
+impl NameLengths {
+    /// Number of leading characters of a name shown in a violation message.
+    const SHOWN: usize = 32;
+
+    fn name(&mut self, owner: &str, name: Option<&str>) {
+        let Some(z_name) = name else {
+            return;
+        };
+        let len = z_name.chars().count();
+        if len > self.max {
+            let shown = z_name.chars().take(Self::SHOWN).collect::<String>();
+            let ellipsis = if len > Self::SHOWN { "..." } else { "" };
+            self.vec.push(ValidationError::ConstraintViolation(
+                format!(
+                    "{owner} name '{shown}{ellipsis}' is too long ({len} > {} characters)",
+                    self.max
+                )
+                .into(),
+            ))
+        }
+    }
+}
+
+impl StatementVisitor for NameLengths {
+    fn agent(&mut self, agent: &Agent) {
+        self.name("Agent", agent.name_as_str())
+    }
+
+    fn group(&mut self, group: &Group) {
+        self.name("Group", group.name_as_str())
+    }
+
+    fn activity(&mut self, activity: &Activity) {
+        if let Some(z_names) = activity.definition().and_then(|x| x.name_as_map()) {
+            for (tag, name) in z_names.iter() {
+                self.name(&format!("Activity <{}> [{tag}]", activity.id()), Some(name))
+            }
+        }
+    }
+}
+
+/// The rule of [Statement::check_file_urls].
+struct FileUrls(Vec<ValidationError>);
+
+impl StatementVisitor for FileUrls {
+    fn attachment(&mut self, attachment: &Attachment) {
+        if let Err(x) = attachment.validate_https() {
+            self.0.push(x)
+        }
+    }
+}
+
+/// The rule of [Statement::check_extensions].
+struct AllowedExtensions<'a> {
+    allowed: &'a [IriString],
+    vec: Vec<ValidationError>,
+}
+
+impl AllowedExtensions<'_> {
+    fn extensions(&mut self, ext: Option<&Extensions>) {
+        if let Some(z_ext) = ext {
+            self.vec.extend(z_ext.validate_allowed(self.allowed))
+        }
+    }
+}
+
+impl StatementVisitor for AllowedExtensions<'_> {
+    fn activity(&mut self, activity: &Activity) {
+        self.extensions(activity.extensions())
+    }
+
+    fn result(&mut self, result: &XResult) {
+        self.extensions(result.extensions())
+    }
+
+    fn context(&mut self, context: &Context) {
+        self.extensions(context.extensions())
+    }
 }
 
 impl StatementId {
-    pub(crate) fn stored(&self) -> Option<&DateTime<Utc>> {
+    /// Return the UUID of this instance if set; `None` otherwise.
+    pub fn id(&self) -> Option<&Uuid> {
+        self.id.as_ref()
+    }
+
+    /// Return the timestamp of when this instance was persisted if set; `None`
+    /// otherwise.
+    pub fn stored(&self) -> Option<&DateTime<Utc>> {
         self.stored.as_ref()
     }
 
@@ -474,19 +810,8 @@ impl Validate for Statement {
             // middle one will be taken care of the same way `stored` is.
             //
             // [1]: https://adl.gitbooks.io/xapi-lrs-conformance-requirements/content/
-            if z_authority.is_group() {
-                let group = z_authority.as_group().unwrap();
-                if !group.is_anonymous() {
-                    vec.push(ValidationError::ConstraintViolation(
-                        "When used as an Authority, A Group must be anonymous".into(),
-                    ))
-                }
-                if group.members().len() != 2 {
-                    vec.push(ValidationError::ConstraintViolation(
-                        "When used as an Authority, an anonymous Group must have 2 members only"
-                            .into(),
-                    ))
-                }
+            if let Err(x) = check_authority(z_authority) {
+                vec.push(x)
             }
         }
         if let Some(z_version) = self.version.as_ref() {
@@ -502,6 +827,20 @@ impl Validate for Statement {
     }
 }
 
+/// Check that an [Actor] used as a [Statement] `authority` is either an
+/// [Agent] or an anonymous [Group] w/ exactly 2 members.
+fn check_authority(actor: &Actor) -> Result<(), ValidationError> {
+    if let Actor::Group(group) = actor
+        && (!group.is_anonymous() || group.members().len() != 2)
+    {
+        return Err(ValidationError::ConstraintViolation(
+            "When used as an Authority, a Group must be anonymous w/ exactly 2 members".into(),
+        ));
+    }
+
+    Ok(())
+}
+
 impl FromStr for Statement {
     type Err = DataError;
 
@@ -677,21 +1016,9 @@ impl StatementBuilder {
     /// Raise [DataError] if the argument is invalid.
     pub fn authority(mut self, val: Actor) -> Result<Self, DataError> {
         val.check_validity()?;
-        // in addition it must satisfy the following constraints for
-        // use as an Authority --see validate():
-        if val.is_group() {
-            let group = val.as_group().unwrap();
-            if !group.is_anonymous() {
-                emit_error!(DataError::Validation(ValidationError::ConstraintViolation(
-                    "When used as an Authority, a Group must be anonymous".into()
-                )))
-            }
-            if group.members().len() != 2 {
-                emit_error!(DataError::Validation(ValidationError::ConstraintViolation(
-                    "When used as an Authority, an anonymous Group must have 2 members only".into()
-                )))
-            }
-        }
+        // in addition it must satisfy the constraints for use as an Authority
+        // --see validate():
+        check_authority(&val)?;
         self._authority = Some(val);
         Ok(self)
     }
@@ -804,4 +1131,190 @@ mod tests {
 
         Statement::from_str(S).unwrap();
     }
+
+    #[traced_test]
+    #[test]
+    fn test_context_team() {
+        const S: &str = r#"{
+"actor":{"objectType":"Group","name":"Team PB","mbox":"mailto:teampb@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"context":{"team":{"objectType":"TYPE","name":"Team PB","mbox":"mailto:teampb@example.com"}}}"#;
+
+        // the actor Group can also be the team...
+        assert!(Statement::from_str(&S.replace("TYPE", "Group")).is_ok());
+        // ...but a team must be a Group
+        assert!(Statement::from_str(&S.replace("TYPE", "Agent")).is_err());
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_substatement_context_revision() {
+        const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{
+  "objectType":"SubStatement",
+  "actor":{"objectType":"Agent","mbox":"mailto:instructor@example.com"},
+  "verb":{"id":"http://adlnet.gov/expapi/verbs/reported","display":{"en-GB":"reported"}},
+  "object":OBJECT,
+  "context":{
+    "instructor":{"objectType":"Group","mbox":"mailto:instructors@example.com"},
+    "revision":"2"}}}"#;
+
+        let activity = r#"{"objectType":"Activity","id":"http://www.example.com/meetings/34534"}"#;
+        let agent = r#"{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"}"#;
+
+        // a Group instructor + a revision are fine w/ an Activity object...
+        assert!(Statement::from_str(&S.replace("OBJECT", activity)).is_ok());
+        // ...but not w/ an Agent one
+        assert!(Statement::from_str(&S.replace("OBJECT", agent)).is_err());
+    }
+
+    #[test]
+    fn test_verb_and_object_ids() {
+        const S1: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+        const S2: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided"},
+"object":{"objectType":"StatementRef","id":"9e13cefd-53d3-4eac-b5ed-2cf6693903bb"}}"#;
+        const S3: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{"objectType":"Agent","mbox":"mailto:agent@example.com"}}"#;
+
+        let s1 = Statement::from_str(S1).unwrap();
+        assert_eq!(
+            s1.verb_id().as_str(),
+            "http://adlnet.gov/expapi/verbs/attended"
+        );
+        assert_eq!(
+            s1.object_iri().map(|x| x.as_str()),
+            Some("http://www.example.com/meetings/occurances/34534")
+        );
+
+        let s2 = Statement::from_str(S2).unwrap();
+        assert_eq!(
+            s2.verb_id().as_str(),
+            "http://adlnet.gov/expapi/verbs/voided"
+        );
+        // a StatementRef's target is a UUID, not an IRI...
+        assert!(s2.object_iri().is_none());
+
+        let s3 = Statement::from_str(S3).unwrap();
+        assert!(s3.object_iri().is_none());
+    }
+
+    #[test]
+    fn test_check_extensions() {
+        const S: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{
+    "objectType":"Activity",
+    "id":"http://www.example.com/meetings/occurances/34534",
+    "definition":{"extensions":{"http://example.com/ext/room":"Kilby"}}},
+"context":{"extensions":{"http://example.com/ext/rogue":true}}}"#;
+
+        let s = Statement::from_str(S).unwrap();
+        // both are allowed...
+        let allowed = [
+            IriString::try_from("http://example.com/ext/room").unwrap(),
+            IriString::try_from("http://example.com/ext/rogue").unwrap(),
+        ];
+        assert!(s.check_extensions(&allowed).is_empty());
+        // rogue extension is not allowed...
+        let errors = s.check_extensions(&allowed[..1]);
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0]
+                .to_string()
+                .contains("http://example.com/ext/rogue")
+        );
+    }
+
+    #[test]
+    fn test_check_name_lengths() {
+        const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{
+    "objectType":"Activity",
+    "id":"http://www.example.com/meetings/occurances/34534",
+    "definition":{"name":{"en":"Example Meeting","fr":"Exemple de réunion"}}}}"#;
+
+        let s = Statement::from_str(S).unwrap();
+        assert!(s.check_name_lengths(18).is_empty());
+        // the French name is too long...
+        let errors = s.check_name_lengths(17);
+        assert_eq!(errors.len(), 1);
+        let msg = errors[0].to_string();
+        assert!(msg.contains("<http://www.example.com/meetings/occurances/34534> [fr]"));
+        assert!(msg.contains("'Exemple de réunion'"));
+        assert!(msg.contains("(18 > 17 characters)"));
+        // ...so are the Agent's + the English one...
+        let errors = s.check_name_lengths(10);
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].to_string().contains("Agent name 'Sample Agent'"));
+    }
+
+    #[test]
+    fn test_check_file_urls() {
+        const A: &str = r#"{
+    "usageType":"http://example.com/attachment-usage/test",
+    "display":{"en-US":"A test attachment"},
+    "contentType":"text/plain",
+    "length":27,
+    "sha2":"495395e777cd98da653df9615d09c0fd6bb2f8d4788394cd53c56a3bfdcd848a",
+    "fileUrl":"URL"}"#;
+        const S: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{
+    "objectType":"SubStatement",
+    "actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+    "verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+    "object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+    "attachments":[SUB]},
+"attachments":[TOP]}"#;
+
+        let https = A.replace("URL", "https://example.com/a.txt");
+        let http = A.replace("URL", "http://example.com/a.txt");
+        let s = Statement::from_str(&S.replace("TOP", &https).replace("SUB", &https)).unwrap();
+        assert!(s.check_file_urls().is_empty());
+        // the SubStatement's Attachments are checked too...
+        let s = Statement::from_str(&S.replace("TOP", &https).replace("SUB", &http)).unwrap();
+        assert_eq!(s.check_file_urls().len(), 1);
+        let s = Statement::from_str(&S.replace("TOP", &http).replace("SUB", &http)).unwrap();
+        assert_eq!(s.check_file_urls().len(), 2);
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_from_json_obj_bounded() {
+        fn nested(depth: usize) -> Map<String, Value> {
+            let mut ext = String::from("null");
+            for _ in 0..depth {
+                ext = format!(r#"{{"a":[{ext}]}}"#);
+            }
+            let json = format!(
+                r#"{{
+"actor":{{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"}},
+"verb":{{"id":"http://adlnet.gov/expapi/verbs/attended"}},
+"object":{{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}},
+"context":{{"extensions":{{"http://example.com/ext/deep":{ext}}}}}}}"#
+            );
+            serde_json::from_str(&json).unwrap()
+        }
+
+        // statement, context, extensions, then 2 levels per {"a":[...]}...
+        assert!(Statement::from_json_obj_bounded(nested(2), 7).is_ok());
+        assert!(Statement::from_json_obj_bounded(nested(2), 6).is_err());
+        assert!(Statement::from_json_obj_bounded(nested(0), 1).is_err());
+        // w/o a bound only serde_json's recursion limit applies...
+        assert!(Statement::from_json_obj(nested(20)).is_ok());
+    }
 }
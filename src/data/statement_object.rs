@@ -16,6 +16,10 @@ use serde_json::Value;
 use std::hash::Hasher;
 use tracing::{debug, error};
 
+/// Properties that only make sense in an [Agent] or a [Group] and should never
+/// appear in an [Activity].
+const AGENT_ONLY_KEYS: [&str; 5] = ["mbox", "mbox_sha1sum", "openid", "account", "member"];
+
 /// Enumeration representing the _subject_ (or _target_) of an _action_ (a
 /// [Verb][1]) carried out by an [Actor][2] (an [Agent] or a [Group]) captured
 /// in a [Statement][5].
@@ -96,6 +100,21 @@ impl<'de> Deserialize<'de> for StatementObject {
         let v: Value = Deserialize::deserialize(deserializer)?;
         match v {
             Value::Object(ref map) => {
+                // NOTE (rsn) 20261016 - xAPI says a missing `objectType` means
+                // an Activity. an object w/o one but carrying Agent/Group only
+                // properties is ambiguous; reject it instead of guessing...
+                let ambiguous = if map.contains_key("objectType") {
+                    None
+                } else {
+                    AGENT_ONLY_KEYS.iter().find(|k| map.contains_key(**k))
+                };
+                if let Some(k) = ambiguous {
+                    let msg = format!(
+                        "Object w/o 'objectType' defaults to Activity but has '{k}'. Set 'objectType' to 'Agent' or 'Group'"
+                    );
+                    error!("{}", msg);
+                    return Err(de::Error::custom(msg));
+                }
                 let ot = map.get("objectType").map_or(
                     {
                         debug!("Missing 'objectType'. Assume 'Activity' + continue");
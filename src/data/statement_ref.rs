@@ -66,6 +66,8 @@ impl Validate for StatementRef {
                 found: self.object_type.to_string().into(),
             })
         }
+        // NOTE (rsn) 20261016 - instances built by deserializing JSON bypass
+        // the builder's checks; catch nil + max UUIDs here as well...
         if self.id.is_max() || self.id.is_nil() {
             vec.push(ValidationError::ConstraintViolation(
                 "ID should not be all 0's or 1's".into(),
@@ -11,7 +11,8 @@ use tracing::warn;
 ///
 /// The `statements` field will contain the result of a **`GET`** _Statement_
 /// Resource. If it is incomplete (due for example to pagination), the rest can
-/// be accessed at the IRL provided by the `more` property.
+/// be accessed at the IRL provided by the `more` property. When it's complete
+/// `more` is omitted altogether rather than serialized as an empty string.
 ///
 #[skip_serializing_none]
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -20,9 +21,10 @@ pub struct StatementResult {
     more: Option<IriString>,
 }
 
+/// Same as [StatementResult] but w/ its Statements in the `ids` format.
 #[skip_serializing_none]
 #[derive(Debug, Serialize)]
-pub(crate) struct StatementResultId {
+pub struct StatementResultId {
     statements: Vec<StatementId>,
     more: Option<IriString>,
 }
@@ -59,15 +61,31 @@ impl StatementResult {
         self.statements.is_empty()
     }
 
+    /// Return TRUE if this and `that` contain the same _Equivalent_
+    /// [Statement]s, w/ the same multiplicity, regardless of their order
+    /// and `more` values. Return FALSE otherwise.
+    pub fn equivalent(&self, that: &StatementResult) -> bool {
+        if self.statements.len() != that.statements.len() {
+            return false;
+        }
+
+        let mut these: Vec<u64> = self.statements.iter().map(|x| x.uid()).collect();
+        let mut those: Vec<u64> = that.statements.iter().map(|x| x.uid()).collect();
+        these.sort_unstable();
+        those.sort_unstable();
+        these == those
+    }
+
     /// Return the `more` field of this instance if set; `None` otherwise.
     pub fn more(&self) -> Option<&IriStr> {
         self.more.as_deref()
     }
 
-    /// Set the `more` field.
+    /// Set the `more` field. An empty argument unsets it.
     ///
-    /// Raise [DataError] if the argument is empty, cannot be parsed as
-    /// an IRI, or the resulting IRI is not a valid URL.
+    /// Raise [DataError] if the argument cannot be parsed as an IRI, or the
+    /// resulting IRI is not a valid URL. When that happens the `more` field
+    /// is left unchanged.
     pub fn set_more(&mut self, val: &str) -> Result<(), DataError> {
         let s = val.trim();
         if s.is_empty() {
@@ -106,10 +124,17 @@ impl StatementResultId {
         }
     }
 
+    /// Same as [StatementResult::set_more].
     pub(crate) fn set_more(&mut self, val: &str) -> Result<(), DataError> {
         let s = val.trim();
-        let iri = IriStr::new(s)?;
-        self.more = Some(iri.to_owned());
+        if s.is_empty() {
+            warn!("Input value is empty. Unset URL");
+            self.more = None;
+        } else {
+            let iri = IriStr::new(s)?;
+            validate_irl(iri)?;
+            self.more = Some(iri.to_owned());
+        }
         Ok(())
     }
 
@@ -117,14 +142,21 @@ impl StatementResultId {
         self.statements.is_empty()
     }
 
-    pub(crate) fn statements(&self) -> &Vec<StatementId> {
+    /// Return a reference to this instance's statements collection.
+    pub fn statements(&self) -> &Vec<StatementId> {
         self.statements.as_ref()
     }
+
+    /// Return the `more` field of this instance if set; `None` otherwise.
+    pub fn more(&self) -> Option<&IriStr> {
+        self.more.as_deref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn test_deserialization() {
@@ -149,4 +181,49 @@ mod tests {
         let sr: StatementResult = serde_json::from_str(SR).unwrap();
         assert_eq!(sr.statements().len(), 1);
     }
+
+    #[test]
+    fn test_invalid_more() {
+        const MORE: &str = "https://lrs.example.com/xapi/statements/more/?sid=1";
+
+        let mut sr = StatementResult::from(vec![]);
+        assert!(sr.set_more(MORE).is_ok());
+        assert_eq!(sr.more().unwrap().as_str(), MORE);
+
+        // neither a relative reference nor an IRL...
+        assert!(sr.set_more("statements/more/?sid=1").is_err());
+        assert!(sr.set_more("http://").is_err());
+        // ...and `more` is left unchanged
+        assert_eq!(sr.more().unwrap().as_str(), MORE);
+
+        let mut sr_id = StatementResultId::from(vec![]);
+        assert!(sr_id.set_more("http://").is_err());
+        assert!(sr_id.more.is_none());
+    }
+
+    #[test]
+    fn test_equivalent() {
+        const S: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/VERB"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+        let s = |verb: &str| Statement::from_str(&S.replace("VERB", verb)).unwrap();
+
+        let mut sr1 = StatementResult::from(vec![s("attended"), s("answered"), s("attended")]);
+        assert!(
+            sr1.set_more("https://lrs.example.com/xapi/statements/more/?sid=1")
+                .is_ok()
+        );
+        let sr2 = StatementResult::from(vec![s("attended"), s("attended"), s("answered")]);
+        assert!(sr1.equivalent(&sr2));
+        assert!(sr2.equivalent(&sr1));
+
+        // same Statements but different multiplicity...
+        let sr3 = StatementResult::from(vec![s("answered"), s("answered"), s("attended")]);
+        assert!(!sr1.equivalent(&sr3));
+        // ...or count
+        let sr4 = StatementResult::from(vec![s("attended"), s("answered")]);
+        assert!(!sr1.equivalent(&sr4));
+    }
 }
@@ -8,6 +8,45 @@ use chrono::{DateTime, Utc};
 use serde::Serialize;
 use tracing::error;
 
+/// Public view of the possible shapes of a Statement resource, suitable for
+/// matching in downstream code.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum StatementView {
+    /// A single [Statement].
+    S(Box<Statement>),
+    /// A single Statement in `ids` format.
+    SId(Box<StatementId>),
+    /// A collection of [Statement]s.
+    SR(StatementResult),
+    /// A collection of Statements in `ids` format.
+    SRId(StatementResultId),
+}
+
+impl From<Statement> for StatementView {
+    fn from(value: Statement) -> Self {
+        StatementView::S(Box::new(value))
+    }
+}
+
+impl From<StatementId> for StatementView {
+    fn from(value: StatementId) -> Self {
+        StatementView::SId(Box::new(value))
+    }
+}
+
+impl From<StatementResult> for StatementView {
+    fn from(value: StatementResult) -> Self {
+        StatementView::SR(value)
+    }
+}
+
+impl From<StatementResultId> for StatementView {
+    fn from(value: StatementResultId) -> Self {
+        StatementView::SRId(value)
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub(crate) enum StatementType {
@@ -17,6 +56,17 @@ pub(crate) enum StatementType {
     SRId(StatementResultId),
 }
 
+impl From<StatementType> for StatementView {
+    fn from(value: StatementType) -> Self {
+        match value {
+            StatementType::S(x) => StatementView::S(x),
+            StatementType::SId(x) => StatementView::SId(x),
+            StatementType::SR(x) => StatementView::SR(x),
+            StatementType::SRId(x) => StatementView::SRId(x),
+        }
+    }
+}
+
 impl StatementType {
     pub fn set_more(&mut self, val: &str) -> Result<(), DataError> {
         match self {
@@ -76,6 +126,26 @@ impl StatementType {
         }
     }
 
+    /// Return the JSON serialized form of each item of this inner instance if
+    /// it's a collection, or of the single instance otherwise.
+    pub fn items_as_json(&self) -> Result<Vec<String>, DataError> {
+        let res = match self {
+            StatementType::S(x) => vec![serde_json::to_string(x)?],
+            StatementType::SId(x) => vec![serde_json::to_string(x)?],
+            StatementType::SR(x) => x
+                .statements()
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<Result<Vec<_>, _>>()?,
+            StatementType::SRId(x) => x
+                .statements()
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        Ok(res)
+    }
+
     /// Return the potentially empty collection of [Attachment]s.
     pub fn attachments(&self) -> Vec<Attachment> {
         match self {
@@ -98,3 +168,48 @@ impl StatementType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+    use uuid::{Uuid, uuid};
+
+    const S: &str = r#"{
+"id":"01932d1e-a584-79d2-b83a-6b380546b21c",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+    const ID: Uuid = uuid!("01932d1e-a584-79d2-b83a-6b380546b21c");
+    const VERB: &str = "http://adlnet.gov/expapi/verbs/attended";
+
+    #[traced_test]
+    #[test]
+    fn test_view() -> Result<(), DataError> {
+        let sr = StatementResult::from(vec![serde_json::from_str(S)?]);
+        match StatementView::from(StatementType::SR(sr)) {
+            StatementView::SR(x) => {
+                assert_eq!(x.statements().len(), 1);
+                assert_eq!(x.statements()[0].verb().id_as_str(), VERB);
+            }
+            x => panic!("Unexpected view: {x:?}"),
+        }
+
+        let sr = StatementResult::from(vec![serde_json::from_str(S)?]);
+        let sr_id = StatementResultId::from(sr);
+        match StatementView::from(StatementType::SRId(sr_id)) {
+            StatementView::SRId(x) => {
+                assert_eq!(x.statements().len(), 1);
+                assert_eq!(x.statements()[0].id(), Some(&ID));
+                assert!(x.more().is_none());
+                // Verbs in `ids` format have no `display`...
+                let json = serde_json::to_value(&x)?;
+                assert_eq!(json["statements"][0]["verb"]["id"], VERB);
+                assert!(json["statements"][0]["verb"].get("display").is_none());
+            }
+            x => panic!("Unexpected view: {x:?}"),
+        }
+
+        Ok(())
+    }
+}
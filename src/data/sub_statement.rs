@@ -222,6 +222,9 @@ impl Validate for SubStatement {
     fn validate(&self) -> Vec<ValidationError> {
         let mut vec = vec![];
 
+        // NOTE (rsn) 20261016 - a SubStatement MUST NOT have an `id`. there's
+        // no such field here, and `deny_unknown_fields` makes deserializing
+        // one that carries it fail before we get to validate it.
         if !self.check_object_type() {
             vec.push(ValidationError::WrongObjectType {
                 expected: ObjectType::SubStatement,
@@ -245,6 +248,8 @@ impl Validate for SubStatement {
                 ))
             }
         }
+        // same checks (sha2, length, content-type, etc.) as those applied to
+        // a Statement's own Attachments...
         if let Some(z_attachments) = self.attachments.as_ref() {
             for att in z_attachments.iter() {
                 vec.extend(att.validate())
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::data::DataError;
+use core::fmt;
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, MapAccess, SeqAccess, Visitor},
+};
+use std::collections::HashSet;
+
+/// Deserialization target that discards everything it reads but fails as soon
+/// as a JSON Object, at any depth, contains the same key more than once.
+///
+/// `serde_json` silently keeps the last value of a duplicated key when
+/// deserializing into a [Map][serde_json::Map] or a structure.
+struct UniqueKeys;
+
+impl<'de> Deserialize<'de> for UniqueKeys {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(UniqueKeysVisitor)
+    }
+}
+
+struct UniqueKeysVisitor;
+
+impl<'de> Visitor<'de> for UniqueKeysVisitor {
+    type Value = UniqueKeys;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(UniqueKeys)
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(UniqueKeys)
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(UniqueKeys)
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(UniqueKeys)
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(UniqueKeys)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(UniqueKeys)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<UniqueKeys>()?.is_some() {}
+        Ok(UniqueKeys)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut keys = HashSet::new();
+        while let Some(k) = map.next_key::<String>()? {
+            if keys.contains(&k) {
+                return Err(de::Error::custom(format!("Duplicate key '{k}'")));
+            }
+            map.next_value::<UniqueKeys>()?;
+            keys.insert(k);
+        }
+        Ok(UniqueKeys)
+    }
+}
+
+/// Raise a [DataError] if `json` is not valid JSON or if any of its Objects
+/// contains the same key more than once.
+pub(crate) fn check_duplicate_keys(json: &[u8]) -> Result<(), DataError> {
+    serde_json::from_slice::<UniqueKeys>(json)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_keys() {
+        assert!(check_duplicate_keys(br#"{"a":1,"b":[{"c":null},{"c":true}]}"#).is_ok());
+        assert!(check_duplicate_keys(br#"[{"a":1},{"a":2.5}]"#).is_ok());
+
+        assert!(check_duplicate_keys(br#"{"a":1,"a":2}"#).is_err());
+        // at any depth...
+        assert!(check_duplicate_keys(br#"{"a":{"b":[{"c":"x","c":"y"}]}}"#).is_err());
+        // and still rejecting malformed JSON...
+        assert!(check_duplicate_keys(br#"{"a":1"#).is_err());
+    }
+}
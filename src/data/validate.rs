@@ -92,7 +92,7 @@ pub enum ValidationError {
     InvalidSha2String,
 
     #[doc(hidden)]
-    #[error("Empty anonymous group")]
+    #[error("Anonymous Group must have at least 1 member")]
     EmptyAnonymousGroup,
 
     #[doc(hidden)]
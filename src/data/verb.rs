@@ -274,6 +274,10 @@ impl<'a> VerbBuilder<'a> {
 
     /// Add the given `label` to the display dictionary keyed by the given `tag`.
     ///
+    /// The `label` is trimmed first; if that leaves it empty, it's ignored and
+    /// no entry is added. A [Verb] built w/o any non-empty label will have no
+    /// `display` at all.
+    ///
     /// Raise a [DataError] if the tag is not a valid Language Tag.
     pub fn display(mut self, tag: &MyLanguageTag, label: &str) -> Result<Self, DataError> {
         add_language!(self._display, tag, label);
@@ -512,4 +516,31 @@ mod tests {
         assert!(iri::<IriSpec>(IRI1_STR).is_ok());
         assert!(iri::<IriSpec>(IRI2_STR).is_ok());
     }
+
+    #[test]
+    fn test_empty_display() -> Result<(), DataError> {
+        let us = MyLanguageTag::from_str("en-US")?;
+
+        let v = Verb::builder()
+            .id("http://adlnet.gov/expapi/verbs/logged-out")?
+            .display(&us, "")?
+            .display(&us, "  ")?
+            .build()?;
+        assert!(v.display_as_map().is_none());
+        assert!(v.display(&us).is_none());
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(
+            json,
+            r#"{"id":"http://adlnet.gov/expapi/verbs/logged-out"}"#
+        );
+
+        // labels are trimmed when added...
+        let v = Verb::builder()
+            .id("http://adlnet.gov/expapi/verbs/logged-out")?
+            .display(&us, " logged out ")?
+            .build()?;
+        assert_eq!(v.display(&us), Some("logged out"));
+
+        Ok(())
+    }
 }
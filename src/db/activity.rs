@@ -4,34 +4,72 @@ use crate::{
     ActivityDefinition, MyError,
     data::{Activity, Canonical, Format},
     db::{
-        RowID,
+        RowID, iri_key,
         schema::{TActivity, TObjActivity},
     },
     emit_db_error,
 };
 use iri_string::types::IriStr;
 use sqlx::{PgPool, types::Json};
-use std::mem;
+use std::{collections::HashMap, mem, sync::Arc};
 use tracing::debug;
 
+/// An `activity` row as we keep it in memory. Its `definition` is shared by
+/// every [Activity] built from it.
+#[derive(Clone)]
+struct SharedActivity {
+    iri: String,
+    definition: Option<Arc<ActivityDefinition>>,
+}
+
+impl From<TActivity> for SharedActivity {
+    fn from(row: TActivity) -> Self {
+        SharedActivity {
+            iri: row.iri,
+            definition: row.definition.map(|x| Arc::new(x.0)),
+        }
+    }
+}
+
+/// The `activity` rows, keyed by their ID, fetched while building one page
+/// of Statements.
+///
+/// A page often references the same Activities, some w/ large definitions.
+/// Passing the same instance when building each of its Statements fetches
+/// each row once and has all the [Activity] instances built from it share the
+/// one copy of its `definition`.
+#[derive(Default)]
+pub(crate) struct PageActivities(HashMap<i32, SharedActivity>);
+
 const FIND: &str = r#"SELECT * FROM activity WHERE id = $1"#;
 
+/// Find an [Activity] given its table row ID, consulting the ones already
+/// fetched for the same `page` first.
 pub(crate) async fn find_activity(
     conn: &PgPool,
     id: i32,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<Activity, MyError> {
+    if let Some(x) = page.0.get(&id) {
+        return build_activity(x.clone(), format);
+    }
+
     match sqlx::query_as::<_, TActivity>(FIND)
         .bind(id)
         .fetch_one(conn)
         .await
     {
-        Ok(x) => build_activity(x, format),
+        Ok(x) => {
+            let x = SharedActivity::from(x);
+            page.0.insert(id, x.clone());
+            build_activity(x, format)
+        }
         Err(x) => emit_db_error!(x, "Failed finding Activity #{}", id),
     }
 }
 
-const FIND_BY_IRI: &str = r#"SELECT * FROM activity WHERE iri = $1"#;
+const FIND_BY_IRI: &str = r#"SELECT * FROM activity WHERE iri_key = $1"#;
 
 /// Find an [Activity] given its IRI identifier.
 ///
@@ -42,11 +80,11 @@ pub(crate) async fn find_activity_by_iri(
     format: &Format,
 ) -> Result<Option<Activity>, MyError> {
     match sqlx::query_as::<_, TActivity>(FIND_BY_IRI)
-        .bind(iri.normalize().to_string().as_str())
+        .bind(iri_key(iri.as_str()))
         .fetch_one(conn)
         .await
     {
-        Ok(x) => Ok(Some(build_activity(x, format)?)),
+        Ok(x) => Ok(Some(build_activity(SharedActivity::from(x), format)?)),
         Err(x) => match x {
             sqlx::Error::RowNotFound => Ok(None),
             x => emit_db_error!(x, "Failed finding Activity ({})", iri),
@@ -54,14 +92,14 @@ pub(crate) async fn find_activity_by_iri(
     }
 }
 
-const FIND_ID: &str = r#"SELECT id FROM activity WHERE iri = $1"#;
+const FIND_ID: &str = r#"SELECT id FROM activity WHERE iri_key = $1"#;
 
 /// Find an [Activity]'s row ID given its IRI identifier.
 ///
 /// Raise [MyError] if an error occurs in the process.
 pub(crate) async fn find_activity_id(conn: &PgPool, iri: &IriStr) -> Result<Option<i32>, MyError> {
     match sqlx::query_as::<_, RowID>(FIND_ID)
-        .bind(iri.normalize().to_string().as_str())
+        .bind(iri_key(iri.as_str()))
         .fetch_one(conn)
         .await
     {
@@ -73,13 +111,14 @@ pub(crate) async fn find_activity_id(conn: &PgPool, iri: &IriStr) -> Result<Opti
     }
 }
 
-const INSERT_IRI: &str = r#"INSERT INTO activity (iri) VALUES ($1)
-ON CONFLICT (iri) DO UPDATE SET iri = EXCLUDED.iri
+const INSERT_IRI: &str = r#"INSERT INTO activity (iri, iri_key) VALUES ($1, $2)
+ON CONFLICT (iri_key) DO UPDATE SET iri_key = EXCLUDED.iri_key
 RETURNING id"#;
 
 pub(crate) async fn insert_activity_iri(conn: &PgPool, iri: &IriStr) -> Result<i32, MyError> {
     match sqlx::query_as::<_, RowID>(INSERT_IRI)
         .bind(iri.as_str())
+        .bind(iri_key(iri.as_str()))
         .fetch_one(conn)
         .await
     {
@@ -89,7 +128,8 @@ pub(crate) async fn insert_activity_iri(conn: &PgPool, iri: &IriStr) -> Result<i
 }
 
 const UPDATE: &str = r#"UPDATE activity SET definition = $2 WHERE id = $1"#;
-const INSERT: &str = r#"INSERT INTO activity (iri, definition) VALUES ($1, $2) RETURNING id"#;
+const INSERT: &str =
+    r#"INSERT INTO activity (iri, iri_key, definition) VALUES ($1, $2, $3) RETURNING id"#;
 
 /// Insert a given [Activity]. On conflict update existing record by merging the
 /// 'definition' values of old and new instances. Always return the row's ID.
@@ -102,7 +142,7 @@ pub(crate) async fn insert_activity(conn: &PgPool, activity: &Activity) -> Resul
     } else {
         let new_definition = activity.definition().unwrap().to_owned();
         match sqlx::query_as::<_, TActivity>(FIND_BY_IRI)
-            .bind(activity.id_as_str())
+            .bind(iri_key(activity.id_as_str()))
             .fetch_one(conn)
             .await
         {
@@ -127,6 +167,7 @@ pub(crate) async fn insert_activity(conn: &PgPool, activity: &Activity) -> Resul
                 sqlx::Error::RowNotFound => {
                     match sqlx::query_as::<_, RowID>(INSERT)
                         .bind(activity.id_as_str())
+                        .bind(iri_key(activity.id_as_str()))
                         .bind(Json(new_definition))
                         .fetch_one(conn)
                         .await
@@ -159,19 +200,20 @@ pub(crate) async fn find_obj_activity(
     conn: &PgPool,
     sid: i32,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<Activity, MyError> {
     match sqlx::query_as::<_, TObjActivity>(FIND_OBJECT)
         .bind(sid)
         .fetch_one(conn)
         .await
     {
-        Ok(x) => Ok(find_activity(conn, x.activity_id, format).await?),
+        Ok(x) => Ok(find_activity(conn, x.activity_id, format, page).await?),
         Err(x) => emit_db_error!(x, "Failed finding Activity object for Statement #{}", sid),
     }
 }
 
-fn build_activity(row: TActivity, format: &Format) -> Result<Activity, MyError> {
-    debug!("row = {:?}", row);
+fn build_activity(row: SharedActivity, format: &Format) -> Result<Activity, MyError> {
+    debug!("iri = {}", row.iri);
     debug!("format = {:?}", format);
     // NOTE (rsn) 20241113 - always set `object_type`...
     let builder = Activity::builder().with_object_type().id(&row.iri)?;
@@ -179,7 +221,7 @@ fn build_activity(row: TActivity, format: &Format) -> Result<Activity, MyError>
         if format.is_ids() {
             Ok(builder.build()?)
         } else {
-            let mut res = builder.definition(z_definition.0)?.build()?;
+            let mut res = builder.shared_definition(z_definition).build()?;
             if format.is_canonical() {
                 res.canonicalize(format.tags());
             }
@@ -189,3 +231,101 @@ fn build_activity(row: TActivity, format: &Format) -> Result<Activity, MyError>
         Ok(builder.build()?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MyLanguageTag, db::MockDB};
+    use std::{ptr, str::FromStr};
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_shared_definition() -> Result<(), MyError> {
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        const IRI: &str = "http://www.example.com/meetings/occurances/heavy";
+
+        let en = MyLanguageTag::from_str("en")?;
+        let fr = MyLanguageTag::from_str("fr")?;
+
+        // a definition w/ a large description...
+        let text = "An example meeting. ".repeat(1_000).trim_end().to_owned();
+        let definition = ActivityDefinition::builder()
+            .name(&en, "heavy meeting")?
+            .description(&en, &text)?
+            .build()?;
+        let activity = Activity::builder()
+            .id(IRI)?
+            .definition(definition)?
+            .build()?;
+        let id = insert_activity(conn, &activity).await?;
+
+        // a page of Statements referencing the same Activity shares 1 copy
+        // of its definition...
+        let mut pa = PageActivities::default();
+        let mut page = vec![];
+        for _ in 0..10 {
+            page.push(find_activity(conn, id, &Format::default(), &mut pa).await?);
+        }
+        let first = page[0].definition().unwrap();
+        assert_eq!(first.description(&en), Some(text.as_str()));
+        assert!(page.iter().all(|x| ptr::eq(x.definition().unwrap(), first)));
+
+        // altering the definition is visible in the next page...
+        let extra = ActivityDefinition::builder()
+            .name(&fr, "réunion lourde")?
+            .build()?;
+        let activity = Activity::builder().id(IRI)?.definition(extra)?.build()?;
+        insert_activity(conn, &activity).await?;
+        let mut pa = PageActivities::default();
+        let updated = find_activity(conn, id, &Format::default(), &mut pa).await?;
+        assert_eq!(updated.name(&fr), Some("réunion lourde"));
+        assert_eq!(updated.name(&en), Some("heavy meeting"));
+
+        Ok(())
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_page_shares_definition() -> Result<(), MyError> {
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        const IRI: &str = "http://www.example.com/meetings/occurances/heavier";
+        const PAGE_LEN: usize = 20;
+
+        let en = MyLanguageTag::from_str("en")?;
+        let text = "Another example meeting. ".repeat(4_000);
+        let definition = ActivityDefinition::builder()
+            .description(&en, text.trim_end())?
+            .build()?;
+        let activity = Activity::builder()
+            .id(IRI)?
+            .definition(definition)?
+            .build()?;
+        let id = insert_activity(conn, &activity).await?;
+
+        let mut pa = PageActivities::default();
+        let mut page = Vec::with_capacity(PAGE_LEN);
+        for _ in 0..PAGE_LEN {
+            page.push(find_activity(conn, id, &Format::default(), &mut pa).await?);
+        }
+
+        // every Activity in the page points to the page's definition...
+        let shared =
+            pa.0.get(&id)
+                .and_then(|x| x.definition.clone())
+                .expect("Failed finding page definition");
+        assert!(
+            page.iter()
+                .all(|x| ptr::eq(x.definition().unwrap(), Arc::as_ptr(&shared)))
+        );
+        // ...and none made a copy of it: it's referenced by the page, by
+        // `shared` and once by each Activity...
+        assert_eq!(Arc::strong_count(&shared), PAGE_LEN + 2);
+
+        Ok(())
+    }
+}
@@ -17,13 +17,16 @@ pub(crate) async fn upsert(
     profile_id: &str,
     document: &str,
 ) -> Result<(), MyError> {
-    sqlx::query(UPSERT)
+    match sqlx::query(UPSERT)
         .bind(activity_id)
         .bind(profile_id)
         .bind(document)
         .execute(conn)
-        .await?;
-    Ok(())
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(x) => emit_db_error!(x, "Failed upsert Activity Profile ({})", profile_id),
+    }
 }
 
 const FIND: &str = r#"SELECT * FROM activity_profile 
@@ -56,6 +59,38 @@ pub(crate) async fn find(
     }
 }
 
+const FIND_MANY: &str = r#"SELECT * FROM activity_profile 
+WHERE activity_id = $1 AND profile_id = ANY($2)"#;
+
+/// Find the `activity_profile` records w/ the given `activity_id` and any of the given
+/// `profile_ids` and return (a) their `profile_id` and `document` pairs, and
+/// (b) timestamp of when the most recent one was last modified.
+///
+/// Raise [MyError] if an error occurs in the process.
+pub(crate) async fn find_many(
+    conn: &PgPool,
+    activity_id: i32,
+    profile_ids: &[&str],
+) -> Result<(Vec<(String, String)>, DateTime<Utc>), MyError> {
+    match sqlx::query_as::<_, TActivityProfile>(FIND_MANY)
+        .bind(activity_id)
+        .bind(profile_ids)
+        .fetch_all(conn)
+        .await
+    {
+        Ok(x) => {
+            let last_updated = x
+                .iter()
+                .map(|x| x.updated)
+                .max()
+                .unwrap_or(DateTime::UNIX_EPOCH);
+            let docs = x.into_iter().map(|x| (x.profile_id, x.document)).collect();
+            Ok((docs, last_updated))
+        }
+        Err(x) => emit_db_error!(x, "Failed finding Profiles of Activity #{}", activity_id),
+    }
+}
+
 const FIND_IDS_SINCE: &str = r#"SELECT * FROM activity_profile 
 WHERE activity_id = $1 AND updated > $2"#;
 const FIND_IDS: &str = r#"SELECT * FROM activity_profile WHERE activity_id = $1"#;
@@ -19,6 +19,7 @@ use std::{
 use tracing::{debug, warn};
 
 /// How to interpret the string `value` column of `ifi` table.
+#[derive(Clone, Copy)]
 enum Kind {
     /// Owner's e-mail address.
     Mbox = 0,
@@ -192,11 +193,56 @@ async fn find_by_uid(conn: &PgPool, uid: u64) -> Result<Option<TActor>, MyError>
     }
 }
 
+const INSERT_MEMBER: &str = r#"
+INSERT INTO member (group_id, agent_id) VALUES ($1, $2) ON CONFLICT DO NOTHING"#;
+
+async fn insert_member(conn: &PgPool, group_id: i32, agent_id: i32) -> Result<(), MyError> {
+    match sqlx::query(INSERT_MEMBER)
+        .bind(group_id)
+        .bind(agent_id)
+        .execute(conn)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(x) => emit_db_error!(
+            x,
+            "Failed linking Agent #{} to Group #{}",
+            agent_id,
+            group_id
+        ),
+    }
+}
+
+const REKEY_AGENT: &str = r#"UPDATE actor SET fp = $1
+WHERE id = (
+    SELECT a.id FROM actor a
+        JOIN actor_ifi ai ON ai.actor_id = a.id
+        JOIN ifi i ON i.id = ai.ifi_id
+    WHERE NOT a.is_group AND i.kind = $2 AND i.value = $3
+    LIMIT 1)
+RETURNING id"#;
+
+/// Find an Agent row linked to the IFI given as a `kind` and `value` pair, and
+/// set its fingerprint to `fp`. Return its row ID if one was found.
+async fn rekey_agent(conn: &PgPool, fp: u64, k: i16, v: &str) -> Result<Option<i32>, MyError> {
+    match sqlx::query_as::<_, RowID>(REKEY_AGENT)
+        .bind(fp as i64)
+        .bind(k)
+        .bind(v)
+        .fetch_optional(conn)
+        .await
+    {
+        Ok(x) => Ok(x.map(|x| x.0)),
+        Err(x) => emit_db_error!(x, "Failed re-keying Agent by IFI ({}, {})", k, v),
+    }
+}
+
 /// Given an [Actor] find the corresponding database row ID and return it. If
 /// the [Actor] is unknown to us, insert it in the database before returning
-/// it's row ID.
+/// it's row ID. A newly inserted [Group] has its members inserted too.
 ///
 /// Raise [MyError] if an error occurs in the process.
+#[async_recursion]
 pub(crate) async fn find_actor_id(conn: &PgPool, actor: &Actor) -> Result<i32, MyError> {
     debug!("actor = {}", actor);
 
@@ -209,9 +255,6 @@ pub(crate) async fn find_actor_id(conn: &PgPool, actor: &Actor) -> Result<i32, M
         Err(x) => return Err(x),
     }
 
-    // didn't find an existing record.  insert it...
-    let actor_id = insert_actor(conn, fp, actor.name_as_str(), actor.is_group()).await?;
-    debug!("actor_id = {}", actor_id);
     let mut kv_pairs = vec![];
     if actor.mbox().is_some() {
         kv_pairs.push((Kind::Mbox, actor.mbox().unwrap().to_string()))
@@ -226,10 +269,33 @@ pub(crate) async fn find_actor_id(conn: &PgPool, actor: &Actor) -> Result<i32, M
         let act = actor.account().unwrap();
         kv_pairs.push((Kind::Account, act.as_joined_str()))
     }
+    // NOTE (rsn) 20261016 - the 'member' migration stores Group members never
+    // seen on their own w/ a placeholder fingerprint. an Agent's fingerprint
+    // only depends on their IFI so adopt such a row if there's one...
+    if !actor.is_group() {
+        for (k, v) in &kv_pairs {
+            if let Some(x) = rekey_agent(conn, fp, *k as i16, v).await? {
+                return Ok(x);
+            }
+        }
+    }
+
+    // didn't find an existing record.  insert it...
+    let actor_id = insert_actor(conn, fp, actor.name_as_str(), actor.is_group()).await?;
+    debug!("actor_id = {}", actor_id);
     for (k, v) in kv_pairs {
         let ifi_id = insert_ifi(conn, k as i16, &v).await?;
         insert_actor_ifi(conn, actor_id, ifi_id).await?;
     }
+    // NOTE (rsn) 20261016 - the `member` rows are what the `agent` filter
+    // relies on to match Statements whose Actor is a Group w/ that Agent as
+    // a member...
+    if let Actor::Group(group) = actor {
+        for member in group.members() {
+            let agent_id = find_actor_id(conn, &Actor::from_agent(member.to_owned())).await?;
+            insert_member(conn, actor_id, agent_id).await?;
+        }
+    }
 
     Ok(actor_id)
 }
@@ -594,8 +660,13 @@ pub(crate) async fn find_person(conn: &PgPool, agent: &Agent) -> Result<Option<P
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{MyEmailAddress, db::MockDB, lrs::User};
-    use std::str::FromStr;
+    use crate::{
+        MyEmailAddress, Statement,
+        db::{MockDB, statement::insert_statement},
+        lrs::User,
+    };
+    use sqlx::migrate::Migrator;
+    use std::{path::Path, str::FromStr};
     use tracing_test::traced_test;
 
     #[traced_test]
@@ -652,4 +723,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_member_migration() -> Result<(), MyError> {
+        const S: &str = r#"{
+"id":"4c5a47ab-5b8c-4dbb-a3d5-9b1d1b1f2a61",
+"actor":{"objectType":"Group","name":"Anonymous","member":[
+  {"objectType":"Agent","mbox":"mailto:seen@example.com"},
+  {"objectType":"Agent","name":"unseen","account":{"homePage":"http://example.com","name":"unseen"}}]},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34536"}}"#;
+        const MEMBERS: &str = "SELECT agent_id FROM member WHERE group_id = $1 ORDER BY 1";
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+        let m = Migrator::new(Path::new("./migrations")).await?;
+
+        let statement = Statement::from_str(S)?;
+        insert_statement(conn, &statement, None).await?;
+        let group = statement.actor();
+        let group_id = find_actor_id(conn, group).await?;
+        let agents: Vec<_> = group
+            .as_group()?
+            .members()
+            .into_iter()
+            .map(|x| Actor::from_agent(x.to_owned()))
+            .collect();
+        let seen_id = find_actor_id(conn, &agents[0]).await?;
+        let unseen_id = find_actor_id(conn, &agents[1]).await?;
+
+        // 1. get back to what was stored before members were recorded, where
+        //    the 2nd member was never seen on their own...
+        for (sql, id) in [
+            ("DELETE FROM member WHERE group_id = $1", group_id),
+            ("DELETE FROM actor_ifi WHERE actor_id = $1", unseen_id),
+            ("DELETE FROM actor WHERE id = $1", unseen_id),
+        ] {
+            sqlx::query(sql).bind(id).execute(conn).await?;
+        }
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = 20261016000002")
+            .execute(conn)
+            .await?;
+
+        // 2. the migration links both members to the Group...
+        m.run(conn).await?;
+        let members = sqlx::query_scalar::<_, i32>(MEMBERS)
+            .bind(group_id)
+            .fetch_all(conn)
+            .await?;
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0], seen_id);
+        let added_id = members[1];
+        assert_ne!(added_id, unseen_id);
+
+        // 3. and the row added for the 2nd one is theirs once seen again...
+        assert_eq!(find_actor_id(conn, &agents[1]).await?, added_id);
+        let row = find_by_uid(conn, agents[1].uid()).await?;
+        let row = row.unwrap();
+        assert_eq!(row.id, added_id);
+        assert_eq!(row.name.unwrap().0, "unseen");
+
+        Ok(())
+    }
 }
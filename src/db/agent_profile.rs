@@ -61,6 +61,38 @@ pub(crate) async fn find(
     }
 }
 
+const FIND_MANY: &str = r#"SELECT * FROM agent_profile 
+WHERE agent_id = $1 AND profile_id = ANY($2)"#;
+
+/// Find the `agent_profile` records w/ the given `agent_id` and any of the given
+/// `profile_ids` and return (a) their `profile_id` and `document` pairs, and
+/// (b) timestamp of when the most recent one was last modified.
+///
+/// Raise [MyError] if an error occurs in the process.
+pub(crate) async fn find_many(
+    conn: &PgPool,
+    agent_id: i32,
+    profile_ids: &[&str],
+) -> Result<(Vec<(String, String)>, DateTime<Utc>), MyError> {
+    match sqlx::query_as::<_, TAgentProfile>(FIND_MANY)
+        .bind(agent_id)
+        .bind(profile_ids)
+        .fetch_all(conn)
+        .await
+    {
+        Ok(x) => {
+            let last_updated = x
+                .iter()
+                .map(|x| x.updated)
+                .max()
+                .unwrap_or(DateTime::UNIX_EPOCH);
+            let docs = x.into_iter().map(|x| (x.profile_id, x.document)).collect();
+            Ok((docs, last_updated))
+        }
+        Err(x) => emit_db_error!(x, "Failed finding Profiles of Agent #{}", agent_id),
+    }
+}
+
 const FIND_IDS_SINCE: &str = r#"SELECT * FROM agent_profile 
 WHERE agent_id = $1 AND updated > $2"#;
 const FIND_IDS: &str = r#"SELECT * FROM agent_profile WHERE agent_id = $1"#;
@@ -8,7 +8,7 @@ use crate::{
     },
     emit_db_error, MyError,
 };
-use sqlx::PgPool;
+use sqlx::{Executor, PgPool, Postgres};
 use tracing::debug;
 
 impl TryFrom<TAttachment> for Attachment {
@@ -73,8 +73,8 @@ const LINK_ATTACHMENT: &str = r#"
 INSERT INTO attachments (statement_id, attachment_id) VALUES ($1, $2)"#;
 
 /// Associate an Attachment to a Statement or SubStatement.
-pub(crate) async fn link_attachment(
-    conn: &PgPool,
+pub(crate) async fn link_attachment<'e, E: Executor<'e, Database = Postgres>>(
+    conn: E,
     statement_id: i32,
     attachment_id: i32,
 ) -> Result<(), MyError> {
@@ -108,7 +108,10 @@ pub(crate) async fn find_attachment(conn: &PgPool, id: i32) -> Result<Attachment
     }
 }
 
-const FIND_ATTACHMENTS: &str = r#"SELECT * FROM attachments WHERE statement_id = $1"#;
+// NOTE (rsn) 20261016 - order by insertion so Attachments are always returned,
+// and streamed, in the same order they were submitted...
+const FIND_ATTACHMENTS: &str = r#"
+SELECT * FROM attachments WHERE statement_id = $1 ORDER BY attachment_id"#;
 
 pub(crate) async fn find_attachments(conn: &PgPool, sid: i32) -> Result<Vec<Attachment>, MyError> {
     debug!("sid = {}", sid);
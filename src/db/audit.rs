@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::{MyError, db::schema::TAudit, emit_db_error};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Serialize, Serializer};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+/// What was done to a Statement by an authenticated user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum AuditAction {
+    /// The Statement was stored.
+    Insert = 0,
+    /// The Statement was voided.
+    Void = 1,
+}
+
+impl TryFrom<i16> for AuditAction {
+    type Error = MyError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AuditAction::Insert),
+            1 => Ok(AuditAction::Void),
+            x => Err(MyError::Runtime(
+                format!("Unknown audit action code {x}").into(),
+            )),
+        }
+    }
+}
+
+/// A single entry in the audit trail of a Statement.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AuditEntry {
+    /// Row ID of the authenticated user who performed the action.
+    pub(crate) user_id: i32,
+    /// What they did.
+    pub(crate) action: AuditAction,
+    /// UUID of the affected Statement.
+    pub(crate) statement_id: Uuid,
+    /// When they did it.
+    #[serde(serialize_with = "timestamp_ser")]
+    pub(crate) timestamp: DateTime<Utc>,
+}
+
+fn timestamp_ser<S>(val: &DateTime<Utc>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.serialize_str(&val.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+impl TryFrom<TAudit> for AuditEntry {
+    type Error = MyError;
+
+    fn try_from(row: TAudit) -> Result<Self, Self::Error> {
+        Ok(AuditEntry {
+            user_id: row.user_id,
+            action: AuditAction::try_from(row.action)?,
+            statement_id: row.statement_uuid,
+            timestamp: row.created,
+        })
+    }
+}
+
+const INSERT: &str = r#"INSERT INTO audit (user_id, action, statement_uuid)
+SELECT $1, $2, UNNEST($3::UUID[])"#;
+
+/// Append to the audit trail one row per Statement UUID w/ a single `INSERT`.
+///
+/// Called w/in the transaction that persists the affected Statements so the
+/// trail and the Statements are either both written or neither is.
+pub(crate) async fn insert_audit(
+    conn: &mut PgConnection,
+    user_id: i32,
+    action: AuditAction,
+    uuids: &[Uuid],
+) -> Result<(), MyError> {
+    match sqlx::query(INSERT)
+        .bind(user_id)
+        .bind(action as i16)
+        .bind(uuids)
+        .execute(conn)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(x) => emit_db_error!(
+            x,
+            "Failed insert audit {:?} of {} Statement(s)",
+            action,
+            uuids.len()
+        ),
+    }
+}
+
+const FIND: &str = r#"SELECT * FROM audit WHERE statement_uuid = $1 ORDER BY id"#;
+
+/// Return the potentially empty audit trail of a Statement given its UUID.
+pub(crate) async fn find_audit(conn: &PgPool, uuid: &Uuid) -> Result<Vec<AuditEntry>, MyError> {
+    match sqlx::query_as::<_, TAudit>(FIND)
+        .bind(uuid)
+        .fetch_all(conn)
+        .await
+    {
+        Ok(rows) => rows.into_iter().map(AuditEntry::try_from).collect(),
+        Err(x) => emit_db_error!(x, "Failed find audit trail of {}", uuid),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_codes() {
+        for action in [AuditAction::Insert, AuditAction::Void] {
+            assert_eq!(AuditAction::try_from(action as i16).unwrap(), action);
+        }
+        assert!(matches!(AuditAction::try_from(2), Err(MyError::Runtime(_))));
+    }
+}
@@ -8,11 +8,11 @@ use crate::{
     },
     db::{
         RowID,
-        activity::{find_activity, insert_activity},
+        activity::{PageActivities, find_activity, insert_activity},
         actor::{find_actor, find_actor_id, find_agent, find_group},
         schema::{TContext, TCtxActivities, TCtxActors},
     },
-    handle_db_error,
+    emit_db_error, handle_db_error,
 };
 use sqlx::PgPool;
 use tracing::debug;
@@ -79,7 +79,7 @@ pub(crate) async fn insert_context(
         Some(x) => sqlx::types::Json(x.clone()),
         _ => sqlx::types::Json(EMPTY_EXTENSIONS),
     };
-    let id = sqlx::query_as::<_, RowID>(INSERT)
+    let context_id = match sqlx::query_as::<_, RowID>(INSERT)
         .bind(ctx.registration())
         .bind(instructor_id)
         .bind(team_id)
@@ -89,8 +89,11 @@ pub(crate) async fn insert_context(
         .bind(statement_ref)
         .bind(extensions)
         .fetch_one(conn)
-        .await?;
-    let context_id = id.0;
+        .await
+    {
+        Ok(x) => x.0,
+        Err(x) => return emit_db_error!(x, "Failed insert Context"),
+    };
 
     // now insert associates such as context_activities, _agents, and _groups...
     if ctx.context_activities().is_some() {
@@ -126,15 +129,16 @@ async fn insert_ctx_activities(
     a: &Activity,
 ) -> Result<(), MyError> {
     let activity_id = insert_activity(conn, a).await?;
-    let _ = sqlx::query(INSERT_CTX_ACTIVITIES)
+    match sqlx::query(INSERT_CTX_ACTIVITIES)
         .bind(context_id)
         .bind(kind)
         .bind(activity_id)
         .execute(conn)
         .await
-        .map_err(MyError::DB)?;
-
-    Ok(())
+    {
+        Ok(_) => Ok(()),
+        Err(x) => emit_db_error!(x, "Failed insert Context #{} Activity", context_id),
+    }
 }
 
 const FIND: &str = r#"SELECT * FROM context WHERE id = $1"#;
@@ -143,14 +147,18 @@ pub(crate) async fn find_context(
     conn: &PgPool,
     id: i32,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<Context, MyError> {
-    let x = sqlx::query_as::<_, TContext>(FIND)
+    let x = match sqlx::query_as::<_, TContext>(FIND)
         .bind(id)
         .fetch_one(conn)
         .await
-        .map_err(MyError::DB)?;
+    {
+        Ok(x) => x,
+        Err(x) => return emit_db_error!(x, "Failed find Context #{}", id),
+    };
 
-    build_context(conn, x, format).await
+    build_context(conn, x, format, page).await
 }
 
 const FIND_CTX_ACTIVITIES: &str = r#"SELECT * FROM ctx_activities WHERE context_id = $1"#;
@@ -159,6 +167,7 @@ async fn find_context_activities(
     conn: &PgPool,
     cid: i32,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<Option<ContextActivities>, MyError> {
     match sqlx::query_as::<_, TCtxActivities>(FIND_CTX_ACTIVITIES)
         .bind(cid)
@@ -171,7 +180,7 @@ async fn find_context_activities(
             // divide the rows by the value of the `kind` column which corresponds
             // to `parent`, `grouping`, `category` and `other` bucket...
             for r in rows {
-                let activity = find_activity(conn, r.activity_id, format).await?;
+                let activity = find_activity(conn, r.activity_id, format, page).await?;
                 match Kind::from(r.kind) {
                     Kind::Parent => builder = builder.parent(activity)?,
                     Kind::Grouping => builder = builder.grouping(activity)?,
@@ -255,7 +264,12 @@ async fn find_context_groups(
     }
 }
 
-async fn build_context(conn: &PgPool, row: TContext, format: &Format) -> Result<Context, MyError> {
+async fn build_context(
+    conn: &PgPool,
+    row: TContext,
+    format: &Format,
+    page: &mut PageActivities,
+) -> Result<Context, MyError> {
     let mut builder = Context::builder();
 
     let registration = row.registration;
@@ -302,7 +316,7 @@ async fn build_context(conn: &PgPool, row: TContext, format: &Format) -> Result<
     }
 
     // context activities...
-    if let Some(context_activities) = find_context_activities(conn, row.id, format).await? {
+    if let Some(context_activities) = find_context_activities(conn, row.id, format, page).await? {
         builder = builder.context_activities(context_activities)?;
     }
 
@@ -4,7 +4,7 @@
 
 use crate::{
     MyError, config,
-    data::{Actor, DataError, Validate},
+    data::{Actor, DataError, Validate, ValidationError},
     db::{activity::find_activity_id, actor::find_actor_id, verb::find_verb_id},
 };
 use chrono::{DateTime, Local, SecondsFormat, Utc};
@@ -39,6 +39,10 @@ pub(crate) struct Filter {
     ascending: bool,
 }
 
+/// Sort order of Statements returned by any listing end-point when the
+/// `ascending` parameter is missing; i.e. most recently stored first.
+pub(crate) const DEFAULT_ASCENDING: bool = false;
+
 impl Filter {
     /// Parse provided parameters (usually extracted from a request) into a
     /// [Filter] instance that will be used for querying sored _Statements_.
@@ -58,7 +62,14 @@ impl Filter {
         ascending: Option<bool>,
     ) -> Result<Self, MyError> {
         let actor_id = if let Some(z_actor) = actor {
-            let actor = Actor::from_str(z_actor)?;
+            // NOTE (rsn) 20261016 - name the parameter so the caller can tell
+            // which part of the request was rejected...
+            let actor = Actor::from_str(z_actor).map_err(|x| {
+                error!("Failed parsing 'agent': {}", z_actor);
+                DataError::Validation(ValidationError::ConstraintViolation(
+                    format!("Invalid 'agent' parameter: {x}").into(),
+                ))
+            })?;
             actor.check_validity().map_err(DataError::Validation)?;
             // find the table row ID for this Agent or Identified Group...
             let id = find_actor_id(conn, &actor).await?;
@@ -108,7 +119,7 @@ impl Filter {
         let related_activities = related_activities.unwrap_or(false);
         let related_agents = related_agents.unwrap_or(false);
         let limit = i32::try_from(limit.unwrap_or(0)).unwrap_or(0);
-        let ascending = ascending.unwrap_or(false);
+        let ascending = ascending.unwrap_or(DEFAULT_ASCENDING);
         let since = if let Some(z_datetime1) = since {
             let x = DateTime::parse_from_rfc3339(z_datetime1).map_err(|x| {
                 error!("Failed parsing 'since': {}", x);
@@ -250,7 +261,10 @@ pub(crate) async fn register_new_filter(conn: &PgPool) -> Result<u64, MyError> {
         }
         Err(x) => {
             error!("Failed registering new filter: {}", x);
-            Err(MyError::DB(x))
+            Err(MyError::DBContext {
+                op: "Failed registering new filter".into(),
+                source: x,
+            })
         }
     }
 }
@@ -284,6 +298,23 @@ pub(crate) async fn drop_stale_filters(conn: &PgPool) {
     }
 }
 
+/// Remove the filter w/ the given ID along w/ its views once the request that
+/// registered it no longer needs them.
+pub(crate) async fn drop_filter(conn: &PgPool, sid: u64) {
+    let Ok(id) = i64::try_from(sid) else {
+        error!("Failed converting {} to i64", sid);
+        return;
+    };
+    if let Err(x) = sqlx::query("DELETE FROM filter WHERE id = $1")
+        .bind(id)
+        .execute(conn)
+        .await
+    {
+        error!("Failed deleting filter #{}: {}", id, x);
+    }
+    drop_views(conn, id).await;
+}
+
 /// Remove all views w/ names matching the pattern we use when creating
 /// intermediate views to process GET /statements requests w/ filter.
 async fn drop_views(conn: &PgPool, id: i64) {
@@ -8,6 +8,7 @@ pub mod activity_profile;
 pub(crate) mod actor;
 pub mod agent_profile;
 pub(crate) mod attachment;
+pub(crate) mod audit;
 pub(crate) mod context;
 pub(crate) mod filter;
 mod mockdb;
@@ -21,12 +22,60 @@ pub mod verb;
 pub(crate) use mockdb::*;
 
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::{FromRow, PgPool};
 
 /// Structure to use when SQL is RETURNING a row ID.
 #[derive(Debug, FromRow)]
 struct RowID(i32);
 
+/// Return the key we use to store and match a Verb or an Activity by their
+/// IRI; i.e. the IRI w/ a lower-case scheme and, when it's all ASCII, host.
+/// This MUST be kept in sync w/ the `normalize_iri` SQL function which applied
+/// the same normalization to the rows stored before that key was introduced.
+/// Strings that don't start w/ a scheme are returned as is.
+fn iri_key(iri: &str) -> String {
+    let Some((scheme, rest)) = iri.split_once(':') else {
+        return iri.to_owned();
+    };
+    let mut chars = scheme.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        || !chars.all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c))
+    {
+        return iri.to_owned();
+    }
+
+    let mut result = scheme.to_ascii_lowercase();
+    result.push(':');
+    let Some(rest) = rest.strip_prefix("//") else {
+        result.push_str(rest);
+        return result;
+    };
+    let (authority, tail) = rest.split_at(rest.find(['/', '?', '#']).unwrap_or(rest.len()));
+    let (user_info, host) = authority.split_at(authority.find('@').map_or(0, |n| n + 1));
+    result.push_str("//");
+    result.push_str(user_info);
+    if host.is_ascii() {
+        result.push_str(&host.to_ascii_lowercase());
+    } else {
+        result.push_str(host);
+    }
+    result.push_str(tail);
+    result
+}
+
+/// Return the key of an in-memory copy of the row w/ the given `id` (or any
+/// other identifier) in the database `conn` is connected to. Row IDs are only
+/// meaningful w/in a given database and, when testing, several Mock DBs may
+/// be in use by the same process.
+fn cache_key<K>(conn: &PgPool, id: K) -> (String, K) {
+    let db_name = conn
+        .connect_options()
+        .get_database()
+        .unwrap_or_default()
+        .to_owned();
+    (db_name, id)
+}
+
 /// Structure to use when SQL computes an aggregate.
 #[derive(Debug, FromRow)]
 struct Count(i64);
@@ -67,7 +116,7 @@ macro_rules! handle_db_error {
             x => {
                 let __msg = format!($($arg),*);
                 tracing::error!("{}: {:?}", __msg, x);
-                Err(MyError::DB(x))
+                Err(MyError::DBContext { op: __msg.into(), source: x })
             }
         }
     };
@@ -80,13 +129,52 @@ macro_rules! emit_db_error {
     ( $err: expr, $( $arg: expr),* ) => {{
         let __msg = format!($($arg),*);
         tracing::error!("{}: {:?}", __msg, $err);
-        Err(MyError::DB($err))
+        Err(MyError::DBContext { op: __msg.into(), source: $err })
     }};
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::MyError;
     use serde_json::{Map, Value};
+    use sqlx::migrate::Migrator;
+    use std::path::Path;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[test]
+    fn test_db_error_context() {
+        let res: Result<(), MyError> =
+            emit_db_error!(sqlx::Error::PoolTimedOut, "Failed filter Statements");
+        let err = res.unwrap_err();
+        assert!(matches!(err, MyError::DBContext { .. }));
+        assert!(
+            err.to_string()
+                .starts_with("DB error: Failed filter Statements: ")
+        );
+        assert!(logs_contain("Failed filter Statements"));
+
+        let res: Result<Option<i32>, MyError> = handle_db_error!(
+            sqlx::Error::PoolTimedOut,
+            None,
+            "Failed finding Verb #{}",
+            42
+        );
+        let err = res.unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("DB error: Failed finding Verb #42: ")
+        );
+        // not-found is not an error...
+        let res: Result<Option<i32>, MyError> = handle_db_error!(
+            sqlx::Error::RowNotFound,
+            None,
+            "Failed finding Verb #{}",
+            42
+        );
+        assert!(res.unwrap().is_none());
+    }
 
     #[test]
     fn test_serde_json_map() {
@@ -102,4 +190,166 @@ mod tests {
         // or by virtue of PartialEq...
         assert!(obj1 == obj2)
     }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_iri_key_matches_sql() -> Result<(), MyError> {
+        const IRIS: [(&str, &str); 8] = [
+            (
+                "HTTP://Example.COM/xapi/verbs#cased",
+                "http://example.com/xapi/verbs#cased",
+            ),
+            // percent-encoding and dot-segments are left alone...
+            ("http://Example.COM/%7euser", "http://example.com/%7euser"),
+            ("http://example.com/%7Euser", "http://example.com/%7Euser"),
+            ("http://EXAMPLE.com/a/../b", "http://example.com/a/../b"),
+            (
+                "HTTPS://User@WWW.Example.ORG:8080/X?Q=1#F",
+                "https://User@www.example.org:8080/X?Q=1#F",
+            ),
+            // ...and so are non-ASCII hosts...
+            ("http://Résumé.example.ORG/x", "http://Résumé.example.ORG/x"),
+            ("URN:ISBN:0451450523", "urn:ISBN:0451450523"),
+            ("not an IRI", "not an IRI"),
+        ];
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        for (iri, expected) in IRIS {
+            assert_eq!(iri_key(iri), expected);
+            let sql = sqlx::query_scalar::<_, String>("SELECT normalize_iri($1)")
+                .bind(iri)
+                .fetch_one(conn)
+                .await?;
+            assert_eq!(sql, expected);
+        }
+
+        Ok(())
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_iri_key_migration() -> Result<(), MyError> {
+        // what the schema looked like before the migration...
+        const UNDO: [&str; 7] = [
+            "ALTER TABLE activity DROP CONSTRAINT activity_iri_key_key",
+            "ALTER TABLE verb DROP CONSTRAINT verb_iri_key_key",
+            "ALTER TABLE activity DROP COLUMN iri_key",
+            "ALTER TABLE verb DROP COLUMN iri_key",
+            "ALTER TABLE verb ADD CONSTRAINT verb_iri_key UNIQUE (iri)",
+            "ALTER TABLE activity ADD CONSTRAINT activity_iri_key UNIQUE (iri)",
+            "DELETE FROM _sqlx_migrations WHERE version = 20261016000001",
+        ];
+        const DOCS: &str =
+            "SELECT activity_id, profile_id, document FROM activity_profile ORDER BY 2, 1";
+
+        let mdb = MockDB::new();
+        let pool = mdb.pool().await;
+        let mut conn = pool.acquire().await?;
+        let m = Migrator::new(Path::new("./migrations")).await?;
+
+        // 1. get back to the schema before the migration...
+        for sql in UNDO {
+            sqlx::query(sql).execute(&mut *conn).await?;
+        }
+
+        // 2. store the same Verb and Activity IRIs in different forms...
+        let mut verbs = vec![];
+        for (iri, display) in [
+            ("HTTP://Example.COM/verbs/v", r#"{"en":"one"}"#),
+            ("http://example.com/verbs/v", r#"{"en":"uno","fr":"un"}"#),
+        ] {
+            let x = sqlx::query_as::<_, RowID>(
+                "INSERT INTO verb (iri, display) VALUES ($1, $2::JSONB) RETURNING id",
+            )
+            .bind(iri)
+            .bind(display)
+            .fetch_one(&mut *conn)
+            .await?;
+            verbs.push(x.0);
+        }
+        let mut activities = vec![];
+        for iri in [
+            "HTTP://Example.COM/activities/a",
+            "http://example.com/activities/a",
+        ] {
+            let x =
+                sqlx::query_as::<_, RowID>("INSERT INTO activity (iri) VALUES ($1) RETURNING id")
+                    .bind(iri)
+                    .fetch_one(&mut *conn)
+                    .await?;
+            activities.push(x.0);
+        }
+        // 'p1' is stored for both + collides when merging; 'p2' is not...
+        for (activity_id, profile_id, doc) in [
+            (activities[0], "p1", "first"),
+            (activities[1], "p1", "second"),
+            (activities[1], "p2", "moved"),
+        ] {
+            sqlx::query(
+                "INSERT INTO activity_profile (activity_id, profile_id, document) VALUES ($1, $2, $3)",
+            )
+            .bind(activity_id)
+            .bind(profile_id)
+            .bind(doc)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        // 3. applying the migration fails, naming the conflict, w/o changing
+        //    or losing anything...
+        let err = m.run(&mut *conn).await.unwrap_err().to_string();
+        assert!(err.contains("Conflicting Activity Profile documents"));
+        assert!(err.contains("profileId 'p1'"));
+        let docs = sqlx::query_as::<_, (i32, String, String)>(DOCS)
+            .fetch_all(&mut *conn)
+            .await?;
+        assert_eq!(
+            docs,
+            vec![
+                (activities[0], "p1".to_owned(), "first".to_owned()),
+                (activities[1], "p1".to_owned(), "second".to_owned()),
+                (activities[1], "p2".to_owned(), "moved".to_owned()),
+            ]
+        );
+
+        // 4. once the conflict is resolved, it merges them into the 1st row...
+        sqlx::query("UPDATE activity_profile SET profile_id = 'p3' WHERE document = 'second'")
+            .execute(&mut *conn)
+            .await?;
+        m.run(&mut *conn).await?;
+        let (iri, display) = sqlx::query_as::<_, (String, String)>(
+            "SELECT iri, display::TEXT FROM verb WHERE iri_key = $1",
+        )
+        .bind("http://example.com/verbs/v")
+        .fetch_one(&mut *conn)
+        .await?;
+        assert_eq!(iri, "HTTP://Example.COM/verbs/v");
+        assert_eq!(display, r#"{"en": "one", "fr": "un"}"#);
+        let count = sqlx::query_as::<_, Count>("SELECT COUNT(*) FROM verb WHERE id = $1")
+            .bind(verbs[1])
+            .fetch_one(&mut *conn)
+            .await?;
+        assert_eq!(count.0, 0);
+
+        let count = sqlx::query_as::<_, Count>("SELECT COUNT(*) FROM activity WHERE iri_key = $1")
+            .bind("http://example.com/activities/a")
+            .fetch_one(&mut *conn)
+            .await?;
+        assert_eq!(count.0, 1);
+        let docs = sqlx::query_as::<_, (i32, String, String)>(DOCS)
+            .fetch_all(&mut *conn)
+            .await?;
+        assert_eq!(
+            docs,
+            vec![
+                (activities[0], "p1".to_owned(), "first".to_owned()),
+                (activities[0], "p2".to_owned(), "moved".to_owned()),
+                (activities[0], "p3".to_owned(), "second".to_owned()),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -3,10 +3,9 @@
 use crate::{
     data::{Extensions, Score, XResult},
     db::{schema::TResult, RowID},
-    MyError,
+    emit_db_error, MyError,
 };
 use sqlx::PgPool;
-use tracing::error;
 
 impl TryFrom<TResult> for XResult {
     type Error = MyError;
@@ -101,12 +100,11 @@ pub(crate) async fn insert_result(
         .bind(duration)
         .bind(extensions)
         .fetch_one(conn)
-        .await
-        .map_err(|x| {
-            error!("Failed insert Result");
-            MyError::DB(x)
-        })?;
-    Ok(Some(x.0))
+        .await;
+    match x {
+        Ok(x) => Ok(Some(x.0)),
+        Err(x) => emit_db_error!(x, "Failed insert Result"),
+    }
 }
 
 const FIND: &str = r#"SELECT * FROM result WHERE id = $1"#;
@@ -118,9 +116,6 @@ pub(crate) async fn find_result(conn: &PgPool, id: i32) -> Result<XResult, MyErr
         .await
     {
         Ok(x) => Ok(x.try_into()?),
-        Err(x) => {
-            error!("Failed find Result #{}", id);
-            Err(MyError::DB(x))
-        }
+        Err(x) => emit_db_error!(x, "Failed find Result #{}", id),
     }
 }
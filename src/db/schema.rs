@@ -82,7 +82,7 @@ pub(crate) struct TActorIfi {
 // ===== verb stuff ===========================================================
 
 /// Representation of a `verb` row.
-#[derive(Debug, FromRow)]
+#[derive(Clone, Debug, FromRow)]
 pub(crate) struct TVerb {
     pub(crate) id: i32,
     pub(crate) iri: String,
@@ -284,3 +284,18 @@ pub(crate) struct TAttachments {
     statement_id: i32,
     pub(crate) attachment_id: i32,
 }
+
+// ===== audit stuff ==========================================================
+
+/// Representation of an `audit` row.
+///
+/// `action` is 0 when the Statement was inserted, and 1 when it was voided.
+#[derive(Debug, FromRow)]
+pub(crate) struct TAudit {
+    #[allow(dead_code)]
+    pub(crate) id: i32,
+    pub(crate) user_id: i32,
+    pub(crate) action: i16,
+    pub(crate) statement_uuid: Uuid,
+    pub(crate) created: DateTime<Utc>,
+}
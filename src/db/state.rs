@@ -79,15 +79,18 @@ pub(crate) async fn upsert(
     s: &SingleResourceParams<'_>,
     document: &str,
 ) -> Result<(), MyError> {
-    sqlx::query(UPSERT)
+    match sqlx::query(UPSERT)
         .bind(s.activity_id)
         .bind(s.agent_id)
         .bind(s.registration)
         .bind(s.state_id)
         .bind(document)
         .execute(conn)
-        .await?;
-    Ok(())
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(x) => emit_db_error!(x, "Failed upsert State w/ {}", s),
+    }
 }
 
 const FIND: &str = r#"SELECT * FROM state
@@ -3,16 +3,17 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::{
-    MyError, StatementResultId,
+    MyError, StatementResultId, config,
     data::{
         Actor, Format, ObjectKind, Statement, StatementId, StatementObject, StatementRef,
         StatementResult, statement_type::StatementType,
     },
     db::{
-        Count, RowID,
-        activity::{find_obj_activity, insert_activity},
+        Count,
+        activity::{PageActivities, find_obj_activity, insert_activity},
         actor::{find_actor, find_actor_id, find_obj_agent, find_obj_group},
         attachment::{find_attachments, insert_attachment, link_attachment},
+        audit::{AuditAction, insert_audit},
         context::{find_context, insert_context},
         filter::Filter,
         result::{find_result, insert_result},
@@ -22,10 +23,11 @@ use crate::{
     },
     emit_db_error, handle_db_error,
 };
-use chrono::{SecondsFormat, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use core::fmt;
 use serde::{Deserialize, Serialize};
-use sqlx::{AssertSqlSafe, Executor, PgPool};
+use sqlx::{AssertSqlSafe, Executor, PgConnection, PgPool, Postgres};
+use std::collections::HashMap;
 use tracing::{debug, error, info};
 use uuid::Uuid;
 
@@ -46,29 +48,88 @@ pub(crate) async fn statement_exists(conn: &PgPool, uuid: &Uuid) -> Result<Optio
 
 const INSERT: &str = r#"INSERT INTO statement (
   fp, uuid, actor_id, verb_id, object_kind, result_id, context_id, timestamp, authority_id, version, exact
-) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING id"#;
+) VALUES "#;
+
+/// Number of bind parameters in each of the [INSERT] rows.
+const INSERT_PARAMS: usize = 11;
+
+/// The values of a Statement's `statement` table row along w/ the IDs of the
+/// rows it's linked to once inserted.
+struct StatementRow<'a> {
+    s: &'a Statement,
+    fp: i64,
+    actor_id: i32,
+    verb_id: i32,
+    object_kind: i16,
+    result_id: Option<i32>,
+    context_id: Option<i32>,
+    timestamp: DateTime<Utc>,
+    authority_id: Option<i32>,
+    version: Option<String>,
+    object: ObjectLink,
+    attachment_ids: Vec<i32>,
+}
+
+/// What a `statement` row's Object association references.
+enum ObjectLink {
+    Activity(i32),
+    Actor(i32),
+    StatementRef(Uuid),
+    SubStatement(i32),
+}
 
 /// Insert the given Statement into the DB.
-pub(crate) async fn insert_statement(conn: &PgPool, s: &Statement) -> Result<(), MyError> {
+///
+/// When `auditor` is set, it's the row ID of the user recorded in the audit
+/// trail as having inserted it.
+pub(crate) async fn insert_statement(
+    conn: &PgPool,
+    s: &Statement,
+    auditor: Option<i32>,
+) -> Result<(), MyError> {
+    let row = prepare_row(conn, s).await?;
+    insert_rows(conn, &[row], auditor).await
+}
+
+/// Insert the given Statements into the DB. Rows in the `statement` table are
+/// inserted in batches of at most `insert_batch_size` using one multi-row
+/// `INSERT` each.
+///
+/// When `auditor` is set, it's the row ID of the user recorded in the audit
+/// trail as having inserted them.
+pub(crate) async fn insert_statements(
+    conn: &PgPool,
+    statements: &[Statement],
+    auditor: Option<i32>,
+) -> Result<(), MyError> {
+    for batch in statements.chunks(config().insert_batch_size) {
+        let mut rows = Vec::with_capacity(batch.len());
+        for s in batch {
+            rows.push(prepare_row(conn, s).await?);
+        }
+        insert_rows(conn, &rows, auditor).await?;
+    }
+
+    Ok(())
+}
+
+/// Find or insert the rows a given Statement's `statement` row references,
+/// or is linked to.
+async fn prepare_row<'a>(conn: &PgPool, s: &'a Statement) -> Result<StatementRow<'a>, MyError> {
     debug!("s = {}", s);
 
     let fp = s.uid() as i64;
 
     // 1. find the Actor's row ID...
-    // let actor = s.actor()?;
-    let actor = s.actor();
-    // let actor_id = find_actor_id(conn, &actor).await?;
-    let actor_id = find_actor_id(conn, actor).await?;
+    let actor_id = find_actor_id(conn, s.actor()).await?;
     debug!("actor_id = {}", actor_id);
 
     // 2. find the Verb's row ID...
-    let verb = s.verb();
-    let verb_id = update_verb(conn, verb).await?;
+    let verb_id = update_verb(conn, s.verb()).await?;
     debug!("verb_id = {}", verb_id);
 
     // 3. find the Object's kind...
-    let object = s.object();
-    let object_kind = object.kind();
+    let object_kind = s.object().kind();
     debug!("object_kind = {}", object_kind);
 
     // 4. find the Result row ID...
@@ -94,61 +155,146 @@ pub(crate) async fn insert_statement(conn: &PgPool, s: &Statement) -> Result<(),
     debug!("version = {:?}", version);
 
     // NOTE (rsn) 20240827 - make sure timestamp is not null
-    // IMPORTANT (rsn) 2024119 - we now also store the serialized JSON string
-    // of the Statement we're storing in the `exact` column.  this will help
-    // us fulfill the `format` requirement for the similarly named variant.
-    let exact = sqlx::types::Json(s);
-
-    // 8. insert into DB...
-    let x = sqlx::query_as::<_, RowID>(INSERT)
-        .bind(fp)
-        .bind(s.id())
-        .bind(actor_id)
-        .bind(verb_id)
-        .bind(object_kind as i16)
-        .bind(result_id)
-        .bind(context_id)
-        .bind(s.timestamp().unwrap_or(&Utc::now()))
-        .bind(authority_id)
-        .bind(version)
-        .bind(exact)
-        .fetch_one(conn)
-        .await
-        .map_err(|x| {
-            error!("Failed insert ({})", s);
-            MyError::DB(x)
-        })?;
-    let sid = x.0;
-    debug!("sid = {}", sid);
+    let timestamp = s.timestamp().copied().unwrap_or_else(Utc::now);
 
-    // use newly assigned statement row ID to insert Object association...
-    match object {
+    // 8. find or insert the Object's row...
+    let object = match s.object() {
         StatementObject::Activity(activity) => {
-            let activity_id = insert_activity(conn, activity).await?;
-            insert_obj_activity(conn, sid, activity_id).await?;
+            ObjectLink::Activity(insert_activity(conn, activity).await?)
         }
         StatementObject::Agent(agent) => {
-            let actor_id = find_actor_id(conn, &Actor::from_agent(agent.clone())).await?;
-            insert_obj_actor(conn, sid, actor_id).await?;
+            ObjectLink::Actor(find_actor_id(conn, &Actor::from_agent(agent.clone())).await?)
         }
         StatementObject::Group(group) => {
-            let actor_id = find_actor_id(conn, &Actor::from_group(group.clone())).await?;
-            insert_obj_actor(conn, sid, actor_id).await?;
+            ObjectLink::Actor(find_actor_id(conn, &Actor::from_group(group.clone())).await?)
         }
         StatementObject::StatementRef(statement_ref) => {
-            let uuid = statement_ref.id();
-            insert_obj_statement_ref(conn, sid, uuid).await?;
+            ObjectLink::StatementRef(*statement_ref.id())
         }
         StatementObject::SubStatement(sub_statement) => {
-            let sub_statement_id = insert_sub_statement(conn, sub_statement).await?;
-            insert_obj_statement(conn, sid, sub_statement_id).await?;
+            ObjectLink::SubStatement(insert_sub_statement(conn, sub_statement).await?)
         }
+    };
+
+    // 9. insert the Attachments' rows...
+    let mut attachment_ids = vec![];
+    for att in s.attachments() {
+        attachment_ids.push(insert_attachment(conn, att).await?);
+    }
+
+    Ok(StatementRow {
+        s,
+        fp,
+        actor_id,
+        verb_id,
+        object_kind: object_kind as i16,
+        result_id,
+        context_id,
+        timestamp,
+        authority_id,
+        version,
+        object,
+        attachment_ids,
+    })
+}
+
+/// Insert the given rows in the `statement` table w/ one `INSERT` and link
+/// each to its Object and Attachments.
+///
+/// When `auditor` is set, the matching audit trail rows are inserted too. It
+/// all happens in the same transaction.
+async fn insert_rows(
+    conn: &PgPool,
+    rows: &[StatementRow<'_>],
+    auditor: Option<i32>,
+) -> Result<(), MyError> {
+    let mut uuids = Vec::with_capacity(rows.len());
+    for r in rows {
+        match r.s.id() {
+            Some(x) => uuids.push(*x),
+            None => {
+                let msg = "Statement to insert has no UUID";
+                error!("{}", msg);
+                return Err(MyError::Runtime(msg.into()));
+            }
+        }
+    }
+
+    let mut sql = INSERT.to_owned();
+    for i in 0..rows.len() {
+        let params = (1..=INSERT_PARAMS)
+            .map(|j| format!("${}", i * INSERT_PARAMS + j))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if i > 0 {
+            sql.push_str(", ");
+        }
+        sql.push_str(&format!("({params})"));
+    }
+    sql.push_str(" RETURNING id, uuid");
+
+    let mut query = sqlx::query_as::<_, (i32, Uuid)>(AssertSqlSafe(sql));
+    for (r, uuid) in rows.iter().zip(&uuids) {
+        // IMPORTANT (rsn) 2024119 - we now also store the serialized JSON string
+        // of the Statement we're storing in the `exact` column.  this will help
+        // us fulfill the `format` requirement for the similarly named variant.
+        query = query
+            .bind(r.fp)
+            .bind(uuid)
+            .bind(r.actor_id)
+            .bind(r.verb_id)
+            .bind(r.object_kind)
+            .bind(r.result_id)
+            .bind(r.context_id)
+            .bind(r.timestamp)
+            .bind(r.authority_id)
+            .bind(r.version.as_deref())
+            .bind(sqlx::types::Json(r.s));
+    }
+    let mut tx = match conn.begin().await {
+        Ok(x) => x,
+        Err(x) => return emit_db_error!(x, "Failed begin insert Statement(s) transaction"),
+    };
+    let inserted = match query.fetch_all(&mut *tx).await {
+        Ok(x) => x,
+        Err(x) => return emit_db_error!(x, "Failed insert {} Statement(s)", rows.len()),
+    };
+
+    // NOTE (rsn) 20261016 - PostgreSQL doesn't guarantee RETURNING rows come
+    // back in the same order as the VALUES. match them by UUID instead...
+    let sids: HashMap<Uuid, i32> = inserted.into_iter().map(|(id, uuid)| (uuid, id)).collect();
+    for (r, uuid) in rows.iter().zip(&uuids) {
+        let Some(&sid) = sids.get(uuid) else {
+            let msg = format!("Missing row ID of inserted Statement {uuid}");
+            error!("{}", msg);
+            return Err(MyError::Runtime(msg.into()));
+        };
+        debug!("sid = {}", sid);
+        link_row(&mut tx, sid, r).await?;
+    }
+    if let Some(user_id) = auditor {
+        insert_audit(&mut tx, user_id, AuditAction::Insert, &uuids).await?;
+    }
+    if let Err(x) = tx.commit().await {
+        return emit_db_error!(x, "Failed commit insert Statement(s) transaction");
+    }
+
+    Ok(())
+}
+
+/// Insert the Object association and Attachments links of a Statement given
+/// its newly assigned `statement` row ID.
+async fn link_row(conn: &mut PgConnection, sid: i32, r: &StatementRow<'_>) -> Result<(), MyError> {
+    match r.object {
+        ObjectLink::Activity(id) => insert_obj_activity(&mut *conn, sid, id).await?,
+        ObjectLink::Actor(id) => insert_obj_actor(&mut *conn, sid, id).await?,
+        ObjectLink::StatementRef(uuid) => insert_obj_statement_ref(&mut *conn, sid, &uuid).await?,
+        ObjectLink::SubStatement(id) => insert_obj_statement(&mut *conn, sid, id).await?,
     }
 
     // finally, the attachments...
-    for att in s.attachments() {
-        let aid = insert_attachment(conn, att).await?;
-        link_attachment(conn, sid, aid).await?;
+    for aid in &r.attachment_ids {
+        link_attachment(&mut *conn, sid, *aid).await?;
     }
 
     Ok(())
@@ -157,8 +303,8 @@ pub(crate) async fn insert_statement(conn: &PgPool, s: &Statement) -> Result<(),
 const INSERT_OBJ_ACTIVITY: &str =
     r#"INSERT INTO obj_activity (statement_id, activity_id) VALUES ($1, $2)"#;
 
-pub(crate) async fn insert_obj_activity(
-    conn: &PgPool,
+pub(crate) async fn insert_obj_activity<'e, E: Executor<'e, Database = Postgres>>(
+    conn: E,
     statement_id: i32,
     activity_id: i32,
 ) -> Result<(), MyError> {
@@ -180,8 +326,8 @@ pub(crate) async fn insert_obj_activity(
 
 const INSERT_OBJ_ACTOR: &str = r#"INSERT INTO obj_actor (statement_id, actor_id) VALUES ($1, $2)"#;
 
-pub(crate) async fn insert_obj_actor(
-    conn: &PgPool,
+pub(crate) async fn insert_obj_actor<'e, E: Executor<'e, Database = Postgres>>(
+    conn: E,
     statement_id: i32,
     actor_id: i32,
 ) -> Result<(), MyError> {
@@ -203,8 +349,8 @@ pub(crate) async fn insert_obj_actor(
 
 const INSERT_OBJ_STATEMENT_REF: &str =
     r#"INSERT INTO obj_statement_ref (statement_id, uuid) VALUES ($1, $2)"#;
-pub(crate) async fn insert_obj_statement_ref(
-    conn: &PgPool,
+pub(crate) async fn insert_obj_statement_ref<'e, E: Executor<'e, Database = Postgres>>(
+    conn: E,
     statement_id: i32,
     uuid: &Uuid,
 ) -> Result<(), MyError> {
@@ -227,8 +373,8 @@ pub(crate) async fn insert_obj_statement_ref(
 const INSERT_OBJ_STATEMENT: &str = r#"
 INSERT INTO obj_statement (statement_id, sub_statement_id) VALUES ($1, $2)"#;
 
-async fn insert_obj_statement(
-    conn: &PgPool,
+async fn insert_obj_statement<'e, E: Executor<'e, Database = Postgres>>(
+    conn: E,
     statement_id: i32,
     sub_statement_id: i32,
 ) -> Result<(), MyError> {
@@ -268,7 +414,9 @@ pub(crate) async fn find_statement_by_uuid(
         .fetch_one(conn)
         .await
     {
-        Ok(x) => Ok(Some(build_statement(conn, x, format).await?)),
+        Ok(x) => Ok(Some(
+            build_statement(conn, x, format, &mut PageActivities::default()).await?,
+        )),
         Err(x) => handle_db_error!(
             x,
             None,
@@ -388,17 +536,22 @@ async fn by_activity(conn: &PgPool, filter: &Filter, view: &str) -> Result<Optio
     // the "Filter Conditions for StatementRefs".
     let mut sql = format!(
         r#"CREATE OR REPLACE VIEW {view} AS
-SELECT * FROM statement WHERE voided = FALSE AND id IN (
-  SELECT statement_id FROM obj_activity WHERE activity_id = {id}
-)"#
+SELECT * FROM statement WHERE voided = FALSE AND (
+  id IN ( SELECT statement_id FROM obj_activity WHERE activity_id = {id} )"#
     );
 
+    // NOTE (rsn) 20261016 - all four `contextActivities` buckets (parent,
+    // grouping, category and other) are stored in `ctx_activities` w/ their
+    // `kind` discriminant; we deliberately match them all.  the disjunction
+    // must stay w/in the parenthesis so voided statements remain excluded.
     if filter.related_activities() {
         let related = format!(
-            r#" OR context_id IN ( SELECT context_id FROM ctx_activities WHERE activity_id = {id} )"#
+            r#"
+  OR context_id IN ( SELECT context_id FROM ctx_activities WHERE activity_id = {id} )"#
         );
         sql.push_str(&related);
     }
+    sql.push_str("\n)");
 
     debug!("sql = {}", sql);
     let safe_sql = AssertSqlSafe(sql);
@@ -494,57 +647,51 @@ pub(crate) struct PagingInfo {
     pub(crate) offset: i32,
     #[doc(hidden)]
     pub(crate) limit: i32,
+    #[doc(hidden)]
+    pub(crate) ascending: bool,
 }
 
 impl fmt::Display for PagingInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {}, {})", self.count, self.offset, self.limit)
+        write!(
+            f,
+            "({}, {}, {}, {})",
+            self.count, self.offset, self.limit, self.ascending
+        )
     }
 }
 
-/// Apply a given [`filter`][1] parameters to select some previously stored
-/// [Statements][2].
-///
-/// IMPORTANT (rsn) 20241114 - [Filter Conditions for StatementRefs][3]:
-///
-/// "_Targeting Statements_ means that one _Statement_ (the _Targeting Statement_)
-/// includes the _Statement ID_ of another _Statement_ (the _Targeted Statement_)
-/// as a [Statement Reference][StatementRef]; i.e. the _Object_ of the _Statement_.
-///
-/// For filter parameters which are not time or sequence based, _Statements_ which
-/// target others (using a [StatementRef] as their _Objects_) meet the filter
-/// condition if the _Targeted Statement_ meets the filter condition."
-///
-/// [1]: crate::Filter
-/// [2]: xapi::Statement
-/// [3]: https://opensource.ieee.org/xapi/xapi-base-standard-documentation/-/blob/main/9274.1.1%20xAPI%20Base%20Standard%20for%20LRSs.md#filter-conditions-for-statementrefs
-pub(crate) async fn find_statements_by_filter(
-    conn: &PgPool,
-    filter: Filter,
-    format: &Format,
-    sid: u64,
-) -> Result<(StatementType, Option<PagingInfo>), MyError> {
-    let view = format!("v{sid}");
-    debug!("view = '{}'", view);
+/// Return the `ORDER BY` clause sorting Statements by their `stored` value,
+/// and row ID for those sharing the same, in the given direction.
+fn order_by(ascending: bool) -> String {
+    let dir = if ascending { "ASC" } else { "DESC" };
+    format!("ORDER BY stored {dir}, id {dir}")
+}
 
+/// Create a database view named `view` selecting the Statements that match
+/// the given `filter`, sorted by their `stored` timestamp as per its
+/// `ascending` flag.
+///
+/// Raise [MyError] if an error occurs in the process.
+async fn create_filter_view(conn: &PgPool, filter: &Filter, view: &str) -> Result<(), MyError> {
     // we build the final SQL from various views constructed based on the values
     // of the set filter discriminants.
     // start filtering by timestamps...
     let mut views = vec![];
     let v1 = format!("{view}a");
-    if (by_time(conn, &filter, &v1).await?).is_some() {
+    if (by_time(conn, filter, &v1).await?).is_some() {
         views.push(v1);
     }
     let v2 = format!("{view}b");
-    if (by_registration(conn, &filter, &v2).await?).is_some() {
+    if (by_registration(conn, filter, &v2).await?).is_some() {
         views.push(v2)
     }
     let v3 = format!("{view}c");
-    if (by_activity(conn, &filter, &v3).await?).is_some() {
+    if (by_activity(conn, filter, &v3).await?).is_some() {
         views.push(v3)
     }
     let v4 = format!("{view}d");
-    if (by_verb(conn, &filter, &v4).await?).is_some() {
+    if (by_verb(conn, filter, &v4).await?).is_some() {
         let sql = format!("select * from {v4}");
         let safe_sql = AssertSqlSafe(sql);
         match sqlx::query_as::<_, TStatement>(safe_sql)
@@ -565,20 +712,20 @@ pub(crate) async fn find_statements_by_filter(
         views.push(v4)
     }
     let v5 = format!("{view}e");
-    if (by_agent(conn, &filter, &v5).await?).is_some() {
+    if (by_agent(conn, filter, &v5).await?).is_some() {
         views.push(v5)
     }
 
-    let sort_order = if filter.ascending() { "ASC" } else { "DESC" };
+    let order_by = order_by(filter.ascending());
     // IMPORTANT (rsn) 20241112 - we store both Statements and SubStatements in
     // the same `statement` table.  now we need to exclude the SubStatements
     // from the result...  SubStatements have NULL as their `exact` column
-    let mut sql = if views.is_empty() {
+    let sql = if views.is_empty() {
         debug!("Views collection is empty. Select ALL...");
         format!(
             r#"CREATE OR REPLACE VIEW {view} AS
 SELECT * FROM statement WHERE voided = FALSE AND exact IS NOT NULL
-ORDER BY stored {sort_order}"#
+{order_by}"#
         )
     } else {
         // all subordinate views by now are successfully created.  create the main one now...
@@ -589,13 +736,18 @@ ORDER BY stored {sort_order}"#
   x.authority_id, x.version, x.exact
 FROM (SELECT * FROM {v} WHERE voided = FALSE AND exact IS NOT NULL) x "#
         );
+        // NOTE (rsn) 20261016 - each subordinate view selects distinct rows
+        // from `statement`, so joining them on `id` yields their intersection;
+        // i.e. the filter discriminants are AND-ed, incl. the Group members
+        // expansion done in `by_agent`...
         while !views.is_empty() {
             let v = views.remove(views.len() - 1);
             sql.push_str(&format!(" JOIN {v} USING (id)"));
         }
         // we'll create the aggregated view taking into account the sort order as
-        // set in `ascending`
-        format!("CREATE OR REPLACE VIEW {view} AS {sql} ORDER BY stored {sort_order}")
+        // set in `ascending`. Statements POSTed together may share the same
+        // `stored` value; their row IDs keep them in insertion order...
+        format!("CREATE OR REPLACE VIEW {view} AS {sql} {order_by}")
     };
 
     debug!("sql = {}", sql);
@@ -604,32 +756,67 @@ FROM (SELECT * FROM {v} WHERE voided = FALSE AND exact IS NOT NULL) x "#
         Ok(x) => info!("Created main {}: {:?}", view, x),
         Err(x) => {
             error!("Failed create main filter view");
-            return Err(MyError::DB(x));
+            return Err(MyError::DBContext {
+                op: "Failed create main filter view".into(),
+                source: x,
+            });
         }
     }
 
+    Ok(())
+}
+
+/// Apply a given [`filter`][1] parameters to select some previously stored
+/// [Statements][2].
+///
+/// IMPORTANT (rsn) 20241114 - [Filter Conditions for StatementRefs][3]:
+///
+/// "_Targeting Statements_ means that one _Statement_ (the _Targeting Statement_)
+/// includes the _Statement ID_ of another _Statement_ (the _Targeted Statement_)
+/// as a [Statement Reference][StatementRef]; i.e. the _Object_ of the _Statement_.
+///
+/// For filter parameters which are not time or sequence based, _Statements_ which
+/// target others (using a [StatementRef] as their _Objects_) meet the filter
+/// condition if the _Targeted Statement_ meets the filter condition."
+///
+/// [1]: crate::Filter
+/// [2]: xapi::Statement
+/// [3]: https://opensource.ieee.org/xapi/xapi-base-standard-documentation/-/blob/main/9274.1.1%20xAPI%20Base%20Standard%20for%20LRSs.md#filter-conditions-for-statementrefs
+pub(crate) async fn find_statements_by_filter(
+    conn: &PgPool,
+    filter: Filter,
+    format: &Format,
+    sid: u64,
+) -> Result<(StatementType, Option<PagingInfo>), MyError> {
+    let view = format!("v{sid}");
+    debug!("view = '{}'", view);
+
+    create_filter_view(conn, &filter, &view).await?;
+
     // knowing the total number of rows in this view guides how we (a) write the
     // SELECT sql statement for the first N rows, as well as (b) the parameters
     // of the _continuation_ call to return the next page...
-    sql = format!("SELECT COUNT(*) AS total FROM {view}");
+    let mut sql = format!("SELECT COUNT(*) AS total FROM {view}");
     let safe_sql = AssertSqlSafe(sql);
-    let count = sqlx::query_as::<_, Count>(safe_sql)
-        .fetch_one(conn)
-        .await?
-        .0;
+    let count = match sqlx::query_as::<_, Count>(safe_sql).fetch_one(conn).await {
+        Ok(x) => x.0,
+        Err(x) => return emit_db_error!(x, "Failed count rows of {}", view),
+    };
     debug!("count = {}", count);
     // convert it to i32...
     let count = i32::try_from(count).unwrap_or(0);
     let offset = 0;
     // finally select 'limit' rows from aggrgate view sorted in correct order...
     let limit = filter.limit();
-    sql = format!("SELECT * FROM {view} LIMIT {limit}");
+    let ascending = filter.ascending();
+    sql = format!("SELECT * FROM {view} {} LIMIT {limit}", order_by(ascending));
 
     let paging_info = if count > limit {
         Some(PagingInfo {
             count,
             offset,
             limit,
+            ascending,
         })
     } else {
         None
@@ -646,16 +833,18 @@ FROM (SELECT * FROM {v} WHERE voided = FALSE AND exact IS NOT NULL) x "#
             debug!("Found {} (statement) row(s)", rows.len());
             if format.is_ids() {
                 let mut statements = vec![];
+                let mut page = PageActivities::default();
                 for r in rows {
-                    let s = build_statement(conn, r, format).await?;
+                    let s = build_statement(conn, r, format, &mut page).await?;
                     statements.push(StatementId::try_from(s)?);
                 }
                 let res = StatementResultId::from(statements);
                 Ok((StatementType::SRId(res), paging_info))
             } else {
                 let mut statements = vec![];
+                let mut page = PageActivities::default();
                 for r in rows {
-                    let s = build_statement(conn, r, format).await?;
+                    let s = build_statement(conn, r, format, &mut page).await?;
                     statements.push(Statement::try_from(s)?);
                 }
                 let res = StatementResult::from(statements);
@@ -666,6 +855,80 @@ FROM (SELECT * FROM {v} WHERE voided = FALSE AND exact IS NOT NULL) x "#
     }
 }
 
+/// Create the database view `v<sid>` selecting the Statements matching the
+/// given `filter` w/o fetching any of them. Use [find_statements_slice] to
+/// then fetch them in batches.
+pub(crate) async fn prepare_filter_view(
+    conn: &PgPool,
+    filter: &Filter,
+    sid: u64,
+) -> Result<(), MyError> {
+    let view = format!("v{sid}");
+    debug!("view = '{}'", view);
+    create_filter_view(conn, filter, &view).await
+}
+
+/// Return at most `limit` Statements from the view `v<sid>`, previously
+/// created by [prepare_filter_view], that sort after the one keyed by `after`;
+/// i.e. its `stored` timestamp and row ID. A `None` `after` starts from the
+/// first one.
+///
+/// Along w/ those Statements return the key of the last one to pass as `after`
+/// when fetching the next slice. An empty result indicates there are no more
+/// Statements left in that view.
+pub(crate) async fn find_statements_slice(
+    conn: &PgPool,
+    sid: u64,
+    after: Option<(DateTime<Utc>, i32)>,
+    limit: i32,
+    ascending: bool,
+    format: &Format,
+) -> Result<(StatementType, Option<(DateTime<Utc>, i32)>), MyError> {
+    // NOTE (rsn) 20261016 - seek past the last seen (stored, id) pair rather
+    // than OFFSET-ing; the cost of the latter grows w/ each slice...
+    let seek = match after {
+        None => "",
+        Some(_) if ascending => "WHERE (stored, id) > ($1, $2)",
+        Some(_) => "WHERE (stored, id) < ($1, $2)",
+    };
+    let sql = format!(
+        "SELECT * FROM v{sid} {seek} {} LIMIT {limit}",
+        order_by(ascending)
+    );
+    debug!("sql = {}", sql);
+    let safe_sql = AssertSqlSafe(sql);
+    let mut query = sqlx::query_as::<_, TStatement>(safe_sql);
+    if let Some((stored, id)) = after {
+        query = query.bind(stored).bind(id);
+    }
+    match query.fetch_all(conn).await {
+        Ok(rows) => {
+            let last = rows.last().map(|x| (x.stored, x.id));
+            if format.is_ids() {
+                let mut statements = vec![];
+                let mut page = PageActivities::default();
+                for r in rows {
+                    let s = build_statement(conn, r, format, &mut page).await?;
+                    statements.push(StatementId::try_from(s)?);
+                }
+                Ok((
+                    StatementType::SRId(StatementResultId::from(statements)),
+                    last,
+                ))
+            } else {
+                let mut statements = vec![];
+                let mut page = PageActivities::default();
+                for r in rows {
+                    let s = build_statement(conn, r, format, &mut page).await?;
+                    statements.push(Statement::try_from(s)?);
+                }
+                Ok((StatementType::SR(StatementResult::from(statements)), last))
+            }
+        }
+        Err(x) => emit_db_error!(x, "Failed fetch Statements slice"),
+    }
+}
+
 /// ...
 pub(crate) async fn find_more_statements(
     conn: &PgPool,
@@ -673,18 +936,25 @@ pub(crate) async fn find_more_statements(
     count: i32,
     mut offset: i32,
     limit: i32,
+    ascending: bool,
     format: &Format,
 ) -> Result<(StatementType, Option<PagingInfo>), MyError> {
     debug!("sid = {}", sid);
     debug!("count = {}", count);
     debug!("offset = {}", offset);
     debug!("limit = {}", limit);
+    debug!("ascending? {}", ascending);
     debug!("format = {}", format);
 
     let view = format!("v{sid}");
     offset += limit;
 
-    let sql = format!("SELECT * FROM {view} OFFSET {offset} LIMIT {limit}");
+    // NOTE (rsn) 20261016 - don't rely on the view's own ORDER BY being
+    // honoured when slicing it...
+    let sql = format!(
+        "SELECT * FROM {view} {} OFFSET {offset} LIMIT {limit}",
+        order_by(ascending)
+    );
     debug!("sql = {}", sql);
     let safe_sql = AssertSqlSafe(sql);
     match sqlx::query_as::<_, TStatement>(safe_sql)
@@ -694,15 +964,17 @@ pub(crate) async fn find_more_statements(
         Ok(rows) => {
             let res = if format.is_ids() {
                 let mut statements = vec![];
+                let mut page = PageActivities::default();
                 for r in rows {
-                    let s = build_statement(conn, r, format).await?;
+                    let s = build_statement(conn, r, format, &mut page).await?;
                     statements.push(StatementId::try_from(s)?);
                 }
                 StatementType::SRId(StatementResultId::from(statements))
             } else {
                 let mut statements = vec![];
+                let mut page = PageActivities::default();
                 for r in rows {
-                    let s = build_statement(conn, r, format).await?;
+                    let s = build_statement(conn, r, format, &mut page).await?;
                     statements.push(Statement::try_from(s)?);
                 }
                 StatementType::SR(StatementResult::from(statements))
@@ -717,6 +989,7 @@ pub(crate) async fn find_more_statements(
                         count,
                         offset,
                         limit,
+                        ascending,
                     })
                 } else {
                     None
@@ -735,6 +1008,11 @@ pub(crate) async fn find_more_statements(
 /// if the 1st flag was TRUE), and finally (c) the row ID of said [Statement]
 /// (only meaningful when the 2nd flag is TRUE).
 ///
+/// A known [Statement] is a valid voiding target if and only if it is neither
+/// (a) a voiding [Statement] itself, nor (b) already voided by another one.
+/// Together these rules guarantee a voiding chain is at most one link long and
+/// that every voided [Statement] has exactly one voider.
+///
 /// Raise [MyError] if the task fails unexpectedly.
 pub(crate) async fn find_statement_to_void(
     conn: &PgPool,
@@ -746,7 +1024,7 @@ pub(crate) async fn find_statement_to_void(
         .await
     {
         Ok(x) => {
-            if x.verb_id == 1 {
+            if x.verb_id == 1 || x.voided {
                 Ok((true, false, 0))
             } else {
                 Ok((true, true, x.id))
@@ -761,12 +1039,39 @@ pub(crate) async fn find_statement_to_void(
     }
 }
 
-const VOID_STATEMENT: &str = r#"UPDATE statement SET voided = TRUE WHERE id = $1"#;
+const VOID_STATEMENTS: &str = r#"UPDATE statement SET voided = TRUE WHERE id = ANY($1)"#;
+
+/// Void the Statements w/ the given row IDs and UUIDs.
+///
+/// When `auditor` is set, it's the row ID of the user recorded, in the same
+/// transaction, in the audit trail as having voided them.
+pub(crate) async fn void_statements(
+    conn: &PgPool,
+    targets: &[(i32, Uuid)],
+    auditor: Option<i32>,
+) -> Result<(), MyError> {
+    if targets.is_empty() {
+        return Ok(());
+    }
 
-pub(crate) async fn void_statement(conn: &PgPool, id: i32) -> Result<(), MyError> {
-    match sqlx::query(VOID_STATEMENT).bind(id).execute(conn).await {
+    let (ids, uuids): (Vec<i32>, Vec<Uuid>) = targets.iter().copied().unzip();
+    let mut tx = match conn.begin().await {
+        Ok(x) => x,
+        Err(x) => return emit_db_error!(x, "Failed begin void Statement(s) transaction"),
+    };
+    if let Err(x) = sqlx::query(VOID_STATEMENTS)
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await
+    {
+        return emit_db_error!(x, "Failed void Statement(s) {:?}", ids);
+    }
+    if let Some(user_id) = auditor {
+        insert_audit(&mut tx, user_id, AuditAction::Void, &uuids).await?;
+    }
+    match tx.commit().await {
         Ok(_) => Ok(()),
-        Err(x) => emit_db_error!(x, "Failed void Statement #{}", id),
+        Err(x) => emit_db_error!(x, "Failed commit void Statement(s) transaction"),
     }
 }
 
@@ -774,6 +1079,7 @@ async fn build_statement(
     conn: &PgPool,
     row: TStatement,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<StatementType, MyError> {
     debug!("----- build_statement -----");
     debug!("row = {:?}", row);
@@ -782,6 +1088,8 @@ async fn build_statement(
     // NOTE (rsn) 20241109 - if format is 'exact' then we almost have everything
     // we need.  what we're missing is the `stored` field.  this field is essential
     // b/c we construct the Consistent-Through response header from its value(s).
+    // nothing else is recomputed; in particular language maps are returned as
+    // they were stored regardless of any Accept-Language header.
     if format.is_exact() {
         let mut stmt = row.exact.unwrap().0;
         stmt.set_stored(row.stored);
@@ -800,7 +1108,7 @@ async fn build_statement(
     };
     debug!("result = {:?}", result);
     let context = match row.context_id {
-        Some(id) => Some(find_context(conn, id, format).await?),
+        Some(id) => Some(find_context(conn, id, format, page).await?),
         _ => None,
     };
     debug!("context = {:?}", context);
@@ -833,7 +1141,7 @@ async fn build_statement(
     let statement_id = row.id;
     let object = match ObjectKind::from(row.object_kind) {
         ObjectKind::ActivityObject => {
-            let obj = find_obj_activity(conn, statement_id, format).await?;
+            let obj = find_obj_activity(conn, statement_id, format, page).await?;
             StatementObject::from_activity(obj)
         }
         ObjectKind::AgentObject => {
@@ -849,7 +1157,7 @@ async fn build_statement(
             StatementObject::from_statement_ref(obj)
         }
         ObjectKind::SubStatementObject => {
-            let obj = find_obj_sub_statement(conn, statement_id, format).await?;
+            let obj = find_obj_sub_statement(conn, statement_id, format, page).await?;
             StatementObject::from_sub_statement(obj)
         }
     };
@@ -877,7 +1185,10 @@ async fn build_statement(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::MockDB;
+    use crate::db::{
+        MockDB,
+        filter::{drop_filter, register_new_filter},
+    };
     use std::str::FromStr;
     use tracing::error;
     use tracing_test::traced_test;
@@ -911,7 +1222,7 @@ mod tests {
 
         let statement =
             serde_json::from_str::<Statement>(S1).expect("Failed deserializing Statement");
-        let tmp = insert_statement(conn, &statement).await;
+        let tmp = insert_statement(conn, &statement, None).await;
         match tmp {
             Ok(_) => Ok(()),
             Err(x) => {
@@ -921,6 +1232,27 @@ mod tests {
         }
     }
 
+    #[traced_test]
+    #[tokio::test]
+    async fn test_insert_no_uuid() -> Result<(), MyError> {
+        const S: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34535"}}"#;
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        // a Statement is assigned a UUID before being persisted. not doing so
+        // is an error, not a panic...
+        let statement = Statement::from_str(S)?;
+        assert!(statement.id().is_none());
+        let res = insert_statement(conn, &statement, None).await;
+        assert!(matches!(res, Err(MyError::Runtime(_))));
+
+        Ok(())
+    }
+
     #[traced_test]
     #[tokio::test]
     async fn test_insert_complex() -> Result<(), MyError> {
@@ -963,7 +1295,7 @@ mod tests {
         assert!(res.is_ok());
         let original = res.unwrap();
 
-        insert_statement(conn, &original).await?;
+        insert_statement(conn, &original, None).await?;
 
         let format = &Format::new("ids", vec![]).unwrap();
         let persisted: Statement = find_statement_by_uuid(conn, ID, false, format)
@@ -980,4 +1312,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_drop_filter() -> Result<(), MyError> {
+        const VIEWS: &str = "SELECT COUNT(*) FROM pg_views WHERE viewname = $1";
+        const FILTERS: &str = "SELECT COUNT(*) FROM filter WHERE id = $1";
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        let filter = Filter::from(
+            conn, None, None, None, None, None, None, None, None, None, None,
+        )
+        .await?;
+        let sid = register_new_filter(conn).await?;
+        prepare_filter_view(conn, &filter, sid).await?;
+        let view = format!("v{sid}");
+
+        let views = sqlx::query_as::<_, Count>(VIEWS)
+            .bind(&view)
+            .fetch_one(conn)
+            .await?;
+        assert_eq!(views.0, 1);
+
+        drop_filter(conn, sid).await;
+
+        let views = sqlx::query_as::<_, Count>(VIEWS)
+            .bind(&view)
+            .fetch_one(conn)
+            .await?;
+        assert_eq!(views.0, 0);
+        let filters = sqlx::query_as::<_, Count>(FILTERS)
+            .bind(sid as i64)
+            .fetch_one(conn)
+            .await?;
+        assert_eq!(filters.0, 0);
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     data::{Actor, Format, ObjectKind, SubStatement, SubStatementObject},
     db::{
         RowID,
-        activity::{find_obj_activity, insert_activity},
+        activity::{PageActivities, find_obj_activity, insert_activity},
         actor::{find_actor, find_actor_id, find_obj_agent, find_obj_group},
         attachment::{find_attachments, insert_attachment, link_attachment},
         context::{find_context, insert_context},
@@ -20,7 +20,7 @@ use crate::{
 };
 use chrono::Utc;
 use sqlx::PgPool;
-use tracing::{debug, error};
+use tracing::debug;
 
 const INSERT_SUBSTATEMENT: &str = r#"INSERT INTO statement (
   fp, actor_id, verb_id, object_kind, result_id, context_id, timestamp
@@ -75,12 +75,11 @@ pub(crate) async fn insert_sub_statement(
         .bind(context_id)
         .bind(sub_statement.timestamp().unwrap_or(&Utc::now()))
         .fetch_one(conn)
-        .await
-        .map_err(|x| {
-            error!("Failed insert ({})", sub_statement);
-            MyError::DB(x)
-        })?;
-    let sub_statement_id = x.0;
+        .await;
+    let sub_statement_id = match x {
+        Ok(x) => x.0,
+        Err(x) => return emit_db_error!(x, "Failed insert ({})", sub_statement),
+    };
     debug!("sub_statement_id = {}", sub_statement_id);
 
     // use newly assigned sub-statement row ID to insert Object association...
@@ -121,6 +120,7 @@ pub(crate) async fn find_obj_sub_statement(
     conn: &PgPool,
     statement_id: i32,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<SubStatement, MyError> {
     match sqlx::query_as::<_, TObjStatement>(FIND_OBJECT)
         .bind(statement_id)
@@ -135,7 +135,7 @@ pub(crate) async fn find_obj_sub_statement(
                 .await
             {
                 Ok(x) => {
-                    let res = build_substatement(conn, x, format).await?;
+                    let res = build_substatement(conn, x, format, page).await?;
                     Ok(res)
                 }
                 Err(x) => emit_db_error!(x, "Failed find SubStatement #{}", id),
@@ -153,6 +153,7 @@ async fn build_substatement(
     conn: &PgPool,
     value: TStatement,
     format: &Format,
+    page: &mut PageActivities,
 ) -> Result<SubStatement, MyError> {
     debug!("value = {:?}", value);
     let actor = find_actor(conn, value.actor_id, format).await?;
@@ -162,7 +163,7 @@ async fn build_substatement(
         _ => None,
     };
     let context = match value.context_id {
-        Some(id) => Some(find_context(conn, id, format).await?),
+        Some(id) => Some(find_context(conn, id, format, page).await?),
         _ => None,
     };
 
@@ -182,7 +183,7 @@ async fn build_substatement(
     // SubStatement Objects are one of Activity, Agent, StatementRef only...
     let object = match ObjectKind::from(value.object_kind) {
         ObjectKind::ActivityObject => {
-            let obj = find_obj_activity(conn, statement_id, format).await?;
+            let obj = find_obj_activity(conn, statement_id, format, page).await?;
             SubStatementObject::from_activity(obj)
         }
         ObjectKind::AgentObject => {
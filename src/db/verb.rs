@@ -1,18 +1,130 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::{
-    MyError, MyLanguageTag,
+    MyError, MyLanguageTag, config,
     data::{Canonical, EMPTY_LANGUAGE_MAP, Format, Verb},
-    db::{Aggregates, RowID, schema::TVerb},
+    db::{Aggregates, RowID, cache_key, iri_key, schema::TVerb},
     emit_db_error,
-    lrs::resources::verbs::{QueryParams, VerbExt, VerbUI},
+    lrs::resources::verbs::{QueryParams, VerbExt, VerbUI, VerbUsage},
 };
+use chrono::{DateTime, Utc};
 use iri_string::types::IriStr;
+use lru::LruCache;
 use sqlx::PgPool;
-use std::str::FromStr;
-use tracing::{debug, error};
+use std::{
+    str::FromStr,
+    sync::{Mutex, MutexGuard, OnceLock},
+};
+use tracing::{debug, error, info};
+
+// NOTE (rsn) 20261016 - every Statement we store or fetch references a Verb.
+// to avoid hitting the DB each time we keep an in-memory copy of the `verb`
+// rows we've seen in an LRU store. they're looked up by row ID when fetching
+// Statements, and by IRI when storing them; hence the 2nd LRU mapping their
+// `iri_key` to their row ID.
+//
+// a cached row is replaced when this server updates the Verb's display. we
+// assume no other server instance updates the same database.
+struct VerbCache {
+    rows: LruCache<(String, i32), TVerb>,
+    ids: LruCache<(String, String), i32>,
+}
+
+static CACHED_VERBS: OnceLock<Mutex<VerbCache>> = OnceLock::new();
+fn cached_verbs() -> Result<MutexGuard<'static, VerbCache>, MyError> {
+    CACHED_VERBS
+        .get_or_init(|| {
+            Mutex::new(VerbCache {
+                rows: LruCache::new(config().verb_cache_len),
+                ids: LruCache::new(config().verb_cache_len),
+            })
+        })
+        .lock()
+        .map_err(|x| MyError::Runtime(format!("Failed locking Verb cache: {x}").into()))
+}
+
+fn find_cached_verb(conn: &PgPool, id: i32) -> Result<Option<TVerb>, MyError> {
+    Ok(cached_verbs()?.rows.get(&cache_key(conn, id)).cloned())
+}
+
+fn find_cached_verb_id(conn: &PgPool, iri: &str) -> Result<Option<i32>, MyError> {
+    Ok(cached_verbs()?
+        .ids
+        .get(&cache_key(conn, iri_key(iri)))
+        .copied())
+}
+
+fn find_cached_verb_by_iri(conn: &PgPool, iri: &str) -> Result<Option<TVerb>, MyError> {
+    let mut cache = cached_verbs()?;
+    match cache.ids.get(&cache_key(conn, iri_key(iri))).copied() {
+        Some(id) => Ok(cache.rows.get(&cache_key(conn, id)).cloned()),
+        None => Ok(None),
+    }
+}
+
+fn cache_verb(conn: &PgPool, row: &TVerb) -> Result<(), MyError> {
+    let mut cache = cached_verbs()?;
+    cache.ids.put(cache_key(conn, iri_key(&row.iri)), row.id);
+    cache.rows.put(cache_key(conn, row.id), row.clone());
+    Ok(())
+}
+
+fn cache_verb_id(conn: &PgPool, iri: &str, id: i32) -> Result<(), MyError> {
+    cached_verbs()?.ids.put(cache_key(conn, iri_key(iri)), id);
+    Ok(())
+}
+
+fn evict_verb(conn: &PgPool, id: i32) -> Result<(), MyError> {
+    cached_verbs()?.rows.pop(&cache_key(conn, id));
+    Ok(())
+}
+
+// number of SELECTs on the `verb` table, per database, used to check that
+// cached rows are not fetched again.
+#[cfg(test)]
+static DB_SELECTS: OnceLock<Mutex<std::collections::HashMap<String, usize>>> = OnceLock::new();
+
+#[cfg(test)]
+fn count_select(conn: &PgPool) {
+    let (db_name, _) = cache_key(conn, ());
+    let mut counts = DB_SELECTS
+        .get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+        .lock()
+        .expect("Failed locking SELECT counts");
+    *counts.entry(db_name).or_default() += 1;
+}
+
+#[cfg(test)]
+fn db_selects(conn: &PgPool) -> usize {
+    let (db_name, _) = cache_key(conn, ());
+    let counts = DB_SELECTS
+        .get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+        .lock()
+        .expect("Failed locking SELECT counts");
+    counts.get(&db_name).copied().unwrap_or_default()
+}
+
+const FIND_ALL: &str = r#"SELECT * FROM verb"#;
 
-const FIND_BY_IRI: &str = r#"SELECT * FROM verb WHERE iri = $1"#;
+/// Load all known [Verb]s into the in-memory cache consulted by [find_verb],
+/// [find_verb_id] and [update_verb]; keeping the last LRS_VERB_CACHE_LEN ones
+/// if there are more. Return the number of rows loaded.
+///
+/// Meant to be called once at start-up.
+pub(crate) async fn warm_up_verb_cache(conn: &PgPool) -> Result<usize, MyError> {
+    match sqlx::query_as::<_, TVerb>(FIND_ALL).fetch_all(conn).await {
+        Ok(rows) => {
+            for r in &rows {
+                cache_verb(conn, r)?;
+            }
+            info!("Cached {} Verb(s)", rows.len());
+            Ok(rows.len())
+        }
+        Err(x) => emit_db_error!(x, "Failed warming up Verb cache"),
+    }
+}
+
+const FIND_BY_IRI: &str = r#"SELECT * FROM verb WHERE iri_key = $1"#;
 
 /// Find a [Verb] given its IRI identifier.
 ///
@@ -20,7 +132,7 @@ const FIND_BY_IRI: &str = r#"SELECT * FROM verb WHERE iri = $1"#;
 #[cfg(test)]
 async fn find_verb_by_iri(conn: &PgPool, iri: &str, format: &Format) -> Result<Verb, MyError> {
     match sqlx::query_as::<_, TVerb>(FIND_BY_IRI)
-        .bind(iri)
+        .bind(iri_key(iri))
         .fetch_one(conn)
         .await
     {
@@ -29,7 +141,7 @@ async fn find_verb_by_iri(conn: &PgPool, iri: &str, format: &Format) -> Result<V
     }
 }
 
-const INSERT: &str = r#"INSERT INTO verb (iri, display) VALUES ($1, $2) RETURNING id"#;
+const INSERT: &str = r#"INSERT INTO verb (iri, iri_key, display) VALUES ($1, $2, $3) RETURNING id"#;
 
 /// Insert a [Verb]. Fails if it already exists.
 pub(crate) async fn insert_verb(conn: &PgPool, v: &Verb) -> Result<i32, MyError> {
@@ -40,73 +152,103 @@ pub(crate) async fn insert_verb(conn: &PgPool, v: &Verb) -> Result<i32, MyError>
     };
     match sqlx::query_as::<_, RowID>(INSERT)
         .bind(iri)
-        .bind(display)
+        .bind(iri_key(iri))
+        .bind(&display)
         .fetch_one(conn)
         .await
     {
-        Ok(x) => Ok(x.0),
+        Ok(x) => {
+            let row = TVerb {
+                id: x.0,
+                iri: iri.to_owned(),
+                display: Some(display),
+            };
+            cache_verb(conn, &row)?;
+            Ok(x.0)
+        }
         Err(x) => emit_db_error!(x, "Failed insert ({})", v),
     }
 }
 
-#[allow(dead_code)]
-const UPDATE: &str = r#"UPDATE verb SET display = $2 WHERE iri = $1 RETURNING id"#;
+const UPDATE: &str = r#"UPDATE verb SET display = COALESCE(display, '{}'::JSONB) || $2::JSONB
+WHERE iri_key = $1 RETURNING *"#;
 
-/// Update an existing [Verb]'s `display` Language Map by adding entries
-/// not already present in the existing copy.
+/// Update an existing [Verb]'s `display` Language Map by adding to it the
+/// new entries, or insert it if it's new. Return its table row ID.
+///
+/// Known Verbs are looked up in the in-memory cache first and the database is
+/// only updated when their `display` would change. The merge itself is done
+/// by the database so concurrent updates do not lose each other's entries.
 pub(crate) async fn update_verb(conn: &PgPool, v: &Verb) -> Result<i32, MyError> {
     let iri = v.id_as_str();
-    match sqlx::query_as::<_, TVerb>(FIND_BY_IRI)
-        .bind(iri)
+    let row = match find_cached_verb_by_iri(conn, iri)? {
+        Some(x) => x,
+        None => {
+            #[cfg(test)]
+            count_select(conn);
+
+            match sqlx::query_as::<_, TVerb>(FIND_BY_IRI)
+                .bind(iri_key(iri))
+                .fetch_optional(conn)
+                .await
+            {
+                Ok(Some(x)) => {
+                    cache_verb(conn, &x)?;
+                    x
+                }
+                Ok(None) => return insert_verb(conn, v).await,
+                Err(x) => return emit_db_error!(x, "Failed find Verb <{}>", iri),
+            }
+        }
+    };
+
+    // if the new verb's display is none or empty then do nothing...
+    let Some(nd) = v.display_as_map().filter(|x| !x.is_empty()) else {
+        return Ok(row.id);
+    };
+    // same if it wouldn't change the known one...
+    if row
+        .display
+        .as_ref()
+        .is_some_and(|y| nd.iter().all(|(k, x)| y.0.get(k) == Some(x.as_str())))
+    {
+        return Ok(row.id);
+    }
+    match sqlx::query_as::<_, TVerb>(UPDATE)
+        .bind(iri_key(iri))
+        .bind(sqlx::types::Json(nd))
         .fetch_one(conn)
         .await
     {
         Ok(x) => {
-            // if the new verb's display is none or empty then do nothing...
-            let new_display = v.display_as_map();
-            match new_display {
-                Some(nd) => {
-                    if nd.is_empty() {
-                        Ok(x.id)
-                    } else {
-                        // replace the old display if it was None, or...
-                        // extend it w/ the new one if it wasn't...
-                        let display = match x.display {
-                            Some(y) => {
-                                let mut old_display = y.0;
-                                old_display.extend(nd.clone());
-                                old_display
-                            }
-                            None => new_display.unwrap().to_owned(),
-                        };
-                        match sqlx::query_as::<_, RowID>(UPDATE)
-                            .bind(iri)
-                            .bind(Some(sqlx::types::Json(display)))
-                            .fetch_one(conn)
-                            .await
-                        {
-                            Ok(x) => Ok(x.0),
-                            Err(x) => emit_db_error!(x, "Failed update display for Verb <{}>", iri),
-                        }
-                    }
-                }
-                None => Ok(x.id),
-            }
+            cache_verb(conn, &x)?;
+            Ok(x.id)
         }
-        Err(_) => insert_verb(conn, v).await,
+        Err(x) => emit_db_error!(x, "Failed update display for Verb <{}>", iri),
     }
 }
 
-const FIND_ID: &str = r#"SELECT id FROM verb WHERE iri = $1"#;
+const FIND_ID: &str = r#"SELECT id FROM verb WHERE iri_key = $1"#;
 
-/// Find the table row ID of a [Verb] given its IRI identifier.
+/// Find the table row ID of a [Verb] given its IRI identifier, consulting the
+/// in-memory cache first.
 pub(crate) async fn find_verb_id(conn: &PgPool, iri: &IriStr) -> Result<Option<i32>, MyError> {
+    if let Some(x) = find_cached_verb_id(conn, iri.as_str())? {
+        return Ok(Some(x));
+    }
+
+    #[cfg(test)]
+    count_select(conn);
+
     match sqlx::query_as::<_, RowID>(FIND_ID)
-        .bind(iri.normalize().to_string().as_str())
+        .bind(iri_key(iri.as_str()))
         .fetch_one(conn)
         .await
     {
-        Ok(x) => Ok(Some(x.0)),
+        Ok(x) => {
+            cache_verb_id(conn, iri.as_str(), x.0)?;
+            Ok(Some(x.0))
+        }
         Err(x) => match x {
             sqlx::Error::RowNotFound => Ok(None),
             x => emit_db_error!(x, "Failed find Verb ({})", iri),
@@ -116,13 +258,24 @@ pub(crate) async fn find_verb_id(conn: &PgPool, iri: &IriStr) -> Result<Option<i
 
 const FIND: &str = r#"SELECT * FROM verb WHERE id = $1"#;
 
+/// Find a [Verb] given its table row ID, consulting the in-memory cache first.
 pub(crate) async fn find_verb(conn: &PgPool, id: i32, format: &Format) -> Result<Verb, MyError> {
+    if let Some(x) = find_cached_verb(conn, id)? {
+        return build_verb(x, format);
+    }
+
+    #[cfg(test)]
+    count_select(conn);
+
     match sqlx::query_as::<_, TVerb>(FIND)
         .bind(id)
         .fetch_one(conn)
         .await
     {
-        Ok(x) => build_verb(x, format),
+        Ok(x) => {
+            cache_verb(conn, &x)?;
+            build_verb(x, format)
+        }
         Err(x) => emit_db_error!(x, "Failed find Verb #{}", id),
     }
 }
@@ -145,7 +298,7 @@ fn build_verb(row: TVerb, format: &Format) -> Result<Verb, MyError> {
 
 pub(crate) async fn ext_find_by_iri(conn: &PgPool, iri: &str) -> Result<VerbExt, MyError> {
     match sqlx::query_as::<_, TVerb>(FIND_BY_IRI)
-        .bind(iri)
+        .bind(iri_key(iri))
         .fetch_one(conn)
         .await
     {
@@ -184,7 +337,10 @@ pub(crate) async fn ext_update(conn: &PgPool, id: i32, v: &Verb) -> Result<(), M
         .execute(conn)
         .await
     {
-        Ok(_) => Ok(()),
+        Ok(_) => {
+            evict_verb(conn, id)?;
+            Ok(())
+        }
         Err(x) => emit_db_error!(x, "Failed updating Verb @{}", id),
     }
 }
@@ -237,12 +393,39 @@ pub(crate) async fn ext_find_some(
     }
 }
 
+const USAGE: &str = r#"SELECT v.id AS rid, v.iri, COUNT(s.id) AS count
+FROM verb v JOIN statement s ON s.verb_id = v.id
+WHERE $1::TIMESTAMPTZ IS NULL OR s.stored > $1
+GROUP BY v.id, v.iri
+ORDER BY v.id"#;
+
+/// Return the number of Statements using each [Verb]. When `since` is set,
+/// only Statements stored after that instant are counted.
+pub(crate) async fn ext_usage(
+    conn: &PgPool,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<VerbUsage>, MyError> {
+    match sqlx::query_as::<_, VerbUsage>(USAGE)
+        .bind(since)
+        .fetch_all(conn)
+        .await
+    {
+        Ok(x) => Ok(x),
+        Err(x) => emit_db_error!(x, "Failed computing Verbs usage"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{MyError, MyLanguageTag, db::MockDB};
+    use crate::{
+        MyError, MyLanguageTag, Statement,
+        db::{Count, MockDB, statement::insert_statement},
+    };
+    use chrono::Duration;
     use std::str::FromStr;
     use tracing_test::traced_test;
+    use uuid::Uuid;
 
     #[traced_test]
     #[tokio::test]
@@ -323,4 +506,163 @@ mod tests {
 
         Ok(())
     }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_verb_cache() -> Result<(), MyError> {
+        const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/xapi/verbs#cached","display":DISPLAY},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+        const IRI: &str = "http://example.com/xapi/verbs#cached";
+        const EN: &str = r#"{"en-US":"cached"}"#;
+        const EN_FR: &str = r#"{"en-US":"cached","fr":"en cache"}"#;
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        let fr = MyLanguageTag::from_str("fr")?;
+        let statement = |display: &str| {
+            let s = S
+                .replace("ID", &Uuid::now_v7().to_string())
+                .replace("DISPLAY", display);
+            serde_json::from_str::<Statement>(&s).expect("Failed deserializing Statement")
+        };
+
+        // 1st Statement using a new Verb looks it up then inserts it...
+        let before = db_selects(conn);
+        insert_statement(conn, &statement(EN), None).await?;
+        assert_eq!(db_selects(conn), before + 1);
+
+        // a 2nd one using the same Verb doesn't...
+        insert_statement(conn, &statement(EN), None).await?;
+        assert_eq!(db_selects(conn), before + 1);
+
+        // ...nor does one extending its display, which also updates the
+        // cached copy...
+        insert_statement(conn, &statement(EN_FR), None).await?;
+        assert_eq!(db_selects(conn), before + 1);
+        let id = find_verb_id(conn, IriStr::new(IRI).unwrap())
+            .await?
+            .expect("Failed finding Verb ID");
+        let v = find_verb(conn, id, &Format::default()).await?;
+        assert_eq!(v.display(&fr), Some("en cache"));
+        assert_eq!(db_selects(conn), before + 1);
+
+        Ok(())
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_update_keeps_db_display() -> Result<(), MyError> {
+        const IRI: &str = "http://example.com/xapi/verbs#merged";
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        let us = MyLanguageTag::from_str("en-US")?;
+        let fr = MyLanguageTag::from_str("fr")?;
+        let de = MyLanguageTag::from_str("de")?;
+
+        let v1 = Verb::builder().id(IRI)?.display(&us, "merged")?.build()?;
+        let id = update_verb(conn, &v1).await?;
+
+        // another writer adds an entry the cached copy doesn't know of...
+        sqlx::query(r#"UPDATE verb SET display = display || '{"de":"verbunden"}' WHERE id = $1"#)
+            .bind(id)
+            .execute(conn)
+            .await
+            .unwrap();
+
+        // extending the display from the stale copy must not drop it...
+        let v2 = Verb::builder().id(IRI)?.display(&fr, "fusionné")?.build()?;
+        assert_eq!(update_verb(conn, &v2).await?, id);
+
+        // ...and the cache is refreshed from the updated row...
+        let before = db_selects(conn);
+        let v = find_verb(conn, id, &Format::default()).await?;
+        assert_eq!(db_selects(conn), before);
+        assert_eq!(v.display(&us), Some("merged"));
+        assert_eq!(v.display(&fr), Some("fusionné"));
+        assert_eq!(v.display(&de), Some("verbunden"));
+
+        Ok(())
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_case_varying_iris() -> Result<(), MyError> {
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        const IRI1: &str = "HTTP://Example.COM/xapi/verbs#cased";
+        const IRI2: &str = "http://example.com/xapi/verbs#cased";
+
+        let us = MyLanguageTag::from_str("en-US")?;
+
+        let v1 = Verb::builder().id(IRI1)?.display(&us, "cased")?.build()?;
+        let id1 = update_verb(conn, &v1).await?;
+        let v2 = Verb::builder().id(IRI2)?.display(&us, "cased")?.build()?;
+        let id2 = update_verb(conn, &v2).await?;
+        // both map to the same row...
+        assert_eq!(id1, id2);
+
+        let count = sqlx::query_as::<_, Count>("SELECT COUNT(*) FROM verb WHERE iri_key = $1")
+            .bind(IRI2)
+            .fetch_one(conn)
+            .await
+            .unwrap();
+        assert_eq!(count.0, 1);
+
+        // ...which keeps the IRI as first received...
+        let v = find_verb(conn, id1, &Format::default()).await?;
+        assert_eq!(v.id_as_str(), IRI1);
+
+        Ok(())
+    }
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_usage_since() -> Result<(), MyError> {
+        const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/xapi/verbs#used","display":{"en":"used"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+        const IRI: &str = "http://example.com/xapi/verbs#used";
+
+        let mdb = MockDB::new();
+        let conn = &mdb.pool().await;
+
+        // 1. 2 Statements stored 2 days ago + 1 stored now...
+        for i in 0..3 {
+            let s = S.replace("ID", &Uuid::now_v7().to_string());
+            let s = serde_json::from_str::<Statement>(&s).expect("Failed deserializing Statement");
+            insert_statement(conn, &s, None).await?;
+            if i == 1 {
+                sqlx::query("UPDATE statement SET stored = stored - INTERVAL '2 days'")
+                    .execute(conn)
+                    .await
+                    .expect("Failed back-dating Statements");
+            }
+        }
+
+        // 2. w/o `since` all 3 are counted...
+        let all = ext_usage(conn, None).await?;
+        let used = all.iter().find(|x| x.iri == IRI).expect("Missing Verb");
+        assert_eq!(used.count, 3);
+
+        // 3. w/ `since` only the last one is...
+        let since = Utc::now() - Duration::days(1);
+        let recent = ext_usage(conn, Some(since)).await?;
+        let used = recent.iter().find(|x| x.iri == IRI).expect("Missing Verb");
+        assert_eq!(used.count, 1);
+
+        // 4. nothing was stored in the future...
+        let later = ext_usage(conn, Some(Utc::now() + Duration::hours(1))).await?;
+        assert!(later.is_empty());
+
+        Ok(())
+    }
 }
@@ -63,6 +63,15 @@ pub enum MyError {
         sqlx::Error,
     ),
 
+    /// DB error w/ a short description of the operation that raised it.
+    #[error("DB error: {op}: {source}")]
+    DBContext {
+        /// The operation that failed; e.g. "Failed filter Statements".
+        op: Cow<'static, str>,
+        /// The underlying database error.
+        source: sqlx::Error,
+    },
+
     /// DB migration error.
     #[error("DB migration error: {0}")]
     DBMigrate(
@@ -102,8 +102,9 @@ pub use data::*;
 pub use db::Aggregates;
 pub use error::MyError;
 pub use lrs::{
-    CONSISTENT_THRU_HDR, CONTENT_TRANSFER_ENCODING_HDR, HASH_HDR, Role, TEST_USER_PLAIN_TOKEN,
-    User, VERSION_HDR, build, resources, verbs::VerbUI,
+    CONSISTENT_THRU_HDR, CONTENT_TRANSFER_ENCODING_HDR, HASH_HDR, PAGE_SIZE_HDR, RESPONSE_TIME_HDR,
+    Role, TEST_USER_PLAIN_TOKEN, User, VERSION_HDR, build, resources,
+    verbs::{VerbUI, VerbUsage},
 };
 use tracing::error;
 
@@ -7,6 +7,7 @@ use crate::{
     config,
     db::{
         filter::{drop_all_filters, drop_stale_filters},
+        verb::warm_up_verb_cache,
         MockDB,
     },
     Mode,
@@ -18,7 +19,7 @@ use rocket::{
 };
 use sqlx::{postgres::PgPoolOptions, PgPool};
 use std::{thread, time::Duration};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Rocket managed state accessible to handlers referencing it in their signature.
 #[derive(Debug)]
@@ -31,7 +32,10 @@ impl DB {
     /// when building LaRS.
     pub(crate) fn fairing(testing: bool) -> DBFairing {
         let mock_db = if testing { Some(MockDB::new()) } else { None };
-        DBFairing { mock_db }
+        // NOTE (rsn) 20261016 - `db_max_connections` is DB_POOL_SIZE if set or
+        // DB_MAX_CONNECTIONS otherwise...
+        let pool_size = config().db_max_connections;
+        DBFairing { mock_db, pool_size }
     }
 
     /// Real workhorse called by the Fairing implementation on Rocket Ignition.
@@ -49,7 +53,7 @@ impl DB {
         let db_connection_str = format!("{}/{}", config().db_server_url, db_name);
         let pool = PgPoolOptions::new()
             .min_connections(config().db_min_connections)
-            .max_connections(config().db_max_connections)
+            .max_connections(fairing.pool_size)
             .acquire_timeout(config().db_acquire_timeout)
             .idle_timeout(config().db_idle_timeout)
             .max_lifetime(config().db_max_lifetime)
@@ -100,6 +104,11 @@ SET email = EXCLUDED.email, credentials = EXCLUDED.credentials"#;
             .expect("Failed applying migration(s)");
         }
 
+        // pre-load known Verbs to spare a DB round-trip for each Statement...
+        if let Err(x) = warm_up_verb_cache(&pool).await {
+            warn!("Failed warming up Verb cache. Ignore + continue: {}", x);
+        }
+
         info!("DB ready!");
         DB { pool }
     }
@@ -107,16 +116,23 @@ SET email = EXCLUDED.email, credentials = EXCLUDED.credentials"#;
     pub(crate) fn pool(&self) -> &PgPool {
         &self.pool
     }
+
+    /// Return the maximum number of connections the pool was configured w/.
+    pub(crate) fn pool_size(&self) -> u32 {
+        self.pool.options().get_max_connections()
+    }
 }
 
 /// Structure for implementing Rocket Fairing. In addition to (1) creating the
 /// Database Connections Pool, (2) setting that pool as a Rocket Managed State,
-/// and (3) ensuring that all migrations are applied to the chosen database
-/// before its use, it's where, when the `testing` flag is TRUE, we (a) create
-/// a Mock DB on Rocket Ignition, and (b) drop said Mock DB on Rocket Shutdown.
+/// (3) ensuring that all migrations are applied to the chosen database before
+/// its use, and (4) warming up the Verb cache, it's where, when the `testing`
+/// flag is TRUE, we (a) create a Mock DB on Rocket Ignition, and (b) drop said
+/// Mock DB on Rocket Shutdown.
 #[derive(Debug)]
 pub(crate) struct DBFairing {
     mock_db: Option<MockDB>,
+    pool_size: u32,
 }
 
 #[rocket::async_trait]
@@ -134,11 +150,17 @@ impl Fairing for DBFairing {
     }
 
     async fn on_liftoff(&self, r: &Rocket<Orbit>) {
-        let conn = r
-            .state::<DB>()
-            .expect("Failed accessing DB on liftoff :(")
-            .pool()
-            .clone();
+        let db = r.state::<DB>().expect("Failed accessing DB on liftoff :(");
+        // w/ fewer connections than workers, requests queue for a connection...
+        let workers = r.config().workers;
+        if (db.pool_size() as usize) < workers {
+            warn!(
+                "DB pool size ({}) is less than the number of Rocket workers ({})",
+                db.pool_size(),
+                workers
+            );
+        }
+        let conn = db.pool().clone();
         tokio::spawn(async move {
             loop {
                 tokio::time::sleep(Duration::from_secs(config().ttl_interval)).await;
@@ -155,3 +177,28 @@ impl Fairing for DBFairing {
         drop_all_filters(conn).await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[tokio::test]
+    async fn test_pool_size() {
+        // neither the default nor the configured value, and not less than
+        // DB_MIN_CONNECTIONS...
+        let pool_size = config().db_max_connections.max(8) + 1;
+        let fairing = DBFairing {
+            mock_db: Some(MockDB::new()),
+            pool_size,
+        };
+        let db = DB::init(&fairing).await;
+
+        assert_eq!(db.pool_size(), pool_size);
+
+        // close the pool before dropping the Mock DB it's connected to...
+        db.pool().close().await;
+        drop(fairing);
+    }
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::{
-    MyError, V200,
+    MyError, V200, config,
     data::{MyLanguageTag, MyVersion},
     runtime_error,
 };
@@ -26,6 +26,22 @@ pub const HASH_HDR: &str = "X-Experience-API-Hash";
 /// The xAPI specific **`X-Experience-API-Consistent-Through`** HTTP header name.
 pub const CONSISTENT_THRU_HDR: &str = "X-Experience-API-Consistent-Through";
 
+/// The non-standard **`X-Experience-API-Page-Size`** HTTP header name. When
+/// present in a `GET /statements` request, its value overrides the server's
+/// default number of Statements per page, provided it doesn't exceed the
+/// configured maximum.
+pub const PAGE_SIZE_HDR: &str = "X-Experience-API-Page-Size";
+
+/// The non-standard **`X-Response-Time-Ms`** HTTP header name. When enabled
+/// its value, in every response, is the number of milliseconds the server
+/// took to process the corresponding request.
+pub const RESPONSE_TIME_HDR: &str = "X-Response-Time-Ms";
+
+/// The **`Prefer`** HTTP header name --see [RFC-7240][1].
+///
+/// [1]: https://www.rfc-editor.org/rfc/rfc7240
+const PREFER_HDR: &str = "Prefer";
+
 /// Valid values for `q` (quality) parameter in `Accept-Language` header.
 const Q_RANGE: RangeInclusive<f32> = RangeInclusive::new(0.0, 1.0);
 
@@ -66,6 +82,16 @@ pub(crate) struct Headers {
     /// header is present and its value is `application/json` this flag
     /// is set to TRUE; otherwise it's set to FALSE.
     is_json_content: bool,
+    /// Boolean flag indicating whether or not the incoming Request has a
+    /// [`Prefer`][1] header w/ a `handling=strict` preference. When TRUE,
+    /// additional validation rules are applied to the Request's payload.
+    ///
+    /// [1]: https://www.rfc-editor.org/rfc/rfc7240
+    is_strict: bool,
+    /// Number of Statements per page the client wants as set in the
+    /// `X-Experience-API-Page-Size` header if present and w/in the server's
+    /// cap; `None` otherwise.
+    page_size: Option<u32>,
 }
 
 /// Encode a language-tag and a quality-value pair used as one of a comma-
@@ -150,6 +176,8 @@ impl Default for Headers {
             if_none_match_etags: ETagValue::Absent,
             languages: vec![],
             is_json_content: false,
+            is_strict: false,
+            page_size: None,
         }
     }
 }
@@ -183,71 +211,12 @@ impl<'r> FromRequest<'r> for Headers {
             }
         };
 
-        let if_match_etags = if req.headers().contains(header::IF_MATCH) {
-            let mut any = false;
-            let mut v1 = vec![];
-            for h in req.headers().get(header::IF_MATCH.as_str()) {
-                let h = h.trim();
-                debug!("h = '{}'", h);
-                if h == "*" {
-                    any = true;
-                    break;
-                } else {
-                    let parts = h.split(',');
-                    for p in parts {
-                        match EntityTag::from_str(p.trim()) {
-                            Ok(x) => v1.push(x),
-                            Err(x) => error!(
-                                "Malformed If-Match ({}) entity tag. Ignore + continue: {}",
-                                p, x
-                            ),
-                        }
-                    }
-                }
-            }
-            if any {
-                ETagValue::Any
-            } else if v1.is_empty() {
-                ETagValue::Absent
-            } else {
-                ETagValue::Set(v1)
-            }
-        } else {
-            ETagValue::Absent
-        };
-
-        let if_none_match_etags = if req.headers().contains(header::IF_NONE_MATCH) {
-            let mut any = false;
-            let mut v2 = vec![];
-            for h in req.headers().get(header::IF_NONE_MATCH.as_str()) {
-                let h = h.trim();
-                debug!("h = '{}'", h);
-                if h == "*" {
-                    any = true;
-                    break;
-                } else {
-                    let parts = h.split(',');
-                    for p in parts {
-                        match EntityTag::from_str(p.trim()) {
-                            Ok(x) => v2.push(x),
-                            Err(x) => error!(
-                                "Malformed If-None-Match ({}) entity tag. Ignore + continue: {}",
-                                p, x
-                            ),
-                        }
-                    }
-                }
-            }
-            if any {
-                ETagValue::Any
-            } else if v2.is_empty() {
-                ETagValue::Absent
-            } else {
-                ETagValue::Set(v2)
-            }
-        } else {
-            ETagValue::Absent
-        };
+        let if_match_etags =
+            process_etags(req.headers().get(header::IF_MATCH.as_str()), "If-Match");
+        let if_none_match_etags = process_etags(
+            req.headers().get(header::IF_NONE_MATCH.as_str()),
+            "If-None-Match",
+        );
 
         let languages = match req.headers().get_one(header::ACCEPT_LANGUAGE.as_str()) {
             Some(x) => process_accept_language(x),
@@ -256,12 +225,36 @@ impl<'r> FromRequest<'r> for Headers {
 
         let is_json_content = req.content_type().is_some_and(|h| *h == ContentType::JSON);
 
+        let is_strict = req.headers().get(PREFER_HDR).any(process_prefer);
+
+        let page_size = match req.headers().get_one(PAGE_SIZE_HDR) {
+            Some(x) => match x.trim().parse::<u32>() {
+                Ok(n) if n > 0 => {
+                    let cap = config().db_max_statements_page_len;
+                    if i32::try_from(n).is_ok_and(|n| n <= cap) {
+                        Some(n)
+                    } else {
+                        warn!("Page size ({}) exceeds cap ({}). Ignore", n, cap);
+                        None
+                    }
+                }
+                _ => {
+                    let msg = format!("Page size header ({x}) is not a positive integer");
+                    error!("{}", msg);
+                    return Outcome::Error((Status::BadRequest, MyError::Runtime(msg.into())));
+                }
+            },
+            None => None,
+        };
+
         Outcome::Success(Headers {
             version: version.to_string(),
             if_match_etags,
             if_none_match_etags,
             languages,
             is_json_content,
+            is_strict,
+            page_size,
         })
     }
 }
@@ -291,6 +284,44 @@ fn process_accept_language(s: &str) -> Vec<MyLanguageTag> {
     tuples.iter().map(|x| x.tag.to_owned()).collect()
 }
 
+/// Aggregate the entity tags found in the given values of the `If-Match` or
+/// `If-None-Match` (`name`) header. Malformed tags are logged + ignored.
+fn process_etags<'a>(values: impl Iterator<Item = &'a str>, name: &str) -> ETagValue {
+    let mut etags = vec![];
+    for h in values {
+        let h = h.trim();
+        debug!("h = '{}'", h);
+        if h == "*" {
+            return ETagValue::Any;
+        }
+
+        for p in h.split(',') {
+            match EntityTag::from_str(p.trim()) {
+                Ok(x) => etags.push(x),
+                Err(x) => error!(
+                    "Malformed {} ({}) entity tag. Ignore + continue: {}",
+                    name, p, x
+                ),
+            }
+        }
+    }
+    if etags.is_empty() {
+        ETagValue::Absent
+    } else {
+        ETagValue::Set(etags)
+    }
+}
+
+/// Return TRUE if one of the comma-separated preferences in a `Prefer` header
+/// value is `handling=strict`; FALSE otherwise. Optional parameters following
+/// a preference (separated by `;`) are ignored.
+fn process_prefer(s: &str) -> bool {
+    s.split(',').any(|p| {
+        let pref = p.split(';').next().unwrap_or_default().replace(' ', "");
+        pref.eq_ignore_ascii_case("handling=strict")
+    })
+}
+
 impl Headers {
     pub(crate) fn has_no_conditionals(&self) -> bool {
         matches!(self.if_match_etags, ETagValue::Absent)
@@ -309,10 +340,7 @@ impl Headers {
         if self.is_match_any() {
             true
         } else {
-            self.match_values()
-                .unwrap()
-                .iter()
-                .any(|x| x.strong_eq(etag))
+            self.if_match_tags().iter().any(|x| x.strong_eq(etag))
         }
     }
 
@@ -320,10 +348,25 @@ impl Headers {
         if self.is_none_match_any() {
             true
         } else {
-            self.none_match_values()
-                .unwrap()
-                .iter()
-                .all(|x| x.weak_ne(etag))
+            self.if_none_match_tags().iter().all(|x| x.weak_ne(etag))
+        }
+    }
+
+    /// Return the entity tags parsed from the `If-Match` header(s). The
+    /// result is empty when the header is absent or its value is `*`.
+    pub(crate) fn if_match_tags(&self) -> &[EntityTag] {
+        match &self.if_match_etags {
+            ETagValue::Set(x) => x,
+            _ => &[],
+        }
+    }
+
+    /// Return the entity tags parsed from the `If-None-Match` header(s). The
+    /// result is empty when the header is absent or its value is `*`.
+    pub(crate) fn if_none_match_tags(&self) -> &[EntityTag] {
+        match &self.if_none_match_etags {
+            ETagValue::Set(x) => x,
+            _ => &[],
         }
     }
 
@@ -335,15 +378,16 @@ impl Headers {
         self.is_json_content
     }
 
-    fn is_match_any(&self) -> bool {
-        matches!(self.if_match_etags, ETagValue::Any)
+    pub(crate) fn is_strict(&self) -> bool {
+        self.is_strict
     }
 
-    fn match_values(&self) -> Option<&Vec<EntityTag>> {
-        match &self.if_match_etags {
-            ETagValue::Set(x) => Some(x),
-            _ => None,
-        }
+    pub(crate) fn page_size(&self) -> Option<u32> {
+        self.page_size
+    }
+
+    fn is_match_any(&self) -> bool {
+        matches!(self.if_match_etags, ETagValue::Any)
     }
 
     fn has_if_none_match(&self) -> bool {
@@ -353,13 +397,6 @@ impl Headers {
     fn is_none_match_any(&self) -> bool {
         matches!(self.if_none_match_etags, ETagValue::Any)
     }
-
-    fn none_match_values(&self) -> Option<&Vec<EntityTag>> {
-        match &self.if_none_match_etags {
-            ETagValue::Set(x) => Some(x),
-            _ => None,
-        }
-    }
 }
 
 #[cfg(test)]
@@ -404,4 +441,34 @@ mod tests {
             assert_eq!(tags[i], cv[i])
         }
     }
+
+    #[test]
+    fn test_prefer_strict() {
+        assert!(process_prefer("handling=strict"));
+        assert!(process_prefer("respond-async, handling = strict; foo=bar"));
+        assert!(process_prefer("Handling=Strict"));
+        assert!(!process_prefer("handling=lenient"));
+        assert!(!process_prefer("return=minimal"));
+        assert!(!process_prefer(""));
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_if_match_tags() {
+        let hdrs = Headers {
+            if_match_etags: process_etags(
+                ["\"abc\", W/\"def\"", "bad-tag", "\"ghi\""].into_iter(),
+                "If-Match",
+            ),
+            if_none_match_etags: process_etags(["*"].into_iter(), "If-None-Match"),
+            ..Default::default()
+        };
+
+        let tags: Vec<_> = hdrs.if_match_tags().iter().map(|x| x.to_string()).collect();
+        assert_eq!(tags, vec!["\"abc\"", "W/\"def\"", "\"ghi\""]);
+        // `*` matches anything but exposes no tags...
+        assert!(hdrs.has_if_match() && hdrs.has_conditionals());
+        assert!(hdrs.if_none_match_tags().is_empty());
+        assert!(Headers::default().if_match_tags().is_empty());
+    }
 }
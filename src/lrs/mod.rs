@@ -14,7 +14,10 @@ mod user;
 
 pub(crate) use db::DB;
 pub(crate) use headers::*;
-pub use headers::{CONSISTENT_THRU_HDR, CONTENT_TRANSFER_ENCODING_HDR, HASH_HDR, VERSION_HDR};
+pub use headers::{
+    CONSISTENT_THRU_HDR, CONTENT_TRANSFER_ENCODING_HDR, HASH_HDR, PAGE_SIZE_HDR, RESPONSE_TIME_HDR,
+    VERSION_HDR,
+};
 pub(crate) use resources::*;
 pub use role::Role;
 pub use server::build;
@@ -25,12 +25,12 @@ use crate::{
     data::Activity,
     db::{
         activity::{find_activity_id, insert_activity_iri},
-        activity_profile::{find, find_ids, remove, upsert},
+        activity_profile::{find, find_ids, find_many, remove, upsert},
     },
     eval_preconditions,
     lrs::{
         DB, Headers, User, WithDocumentOrIDs, emit_doc_response, etag_from_str, no_content,
-        resources::WithETag,
+        resources::{WithETag, doc_as_json, split_profile_ids},
     },
 };
 use chrono::{DateTime, Utc};
@@ -257,10 +257,11 @@ async fn delete(
 /// Fetch a single document with the given id, or if `since` is specified,
 /// Profile ids of all Profile documents for an Activity that have been stored
 /// or updated since the specified Timestamp (exclusive).
-#[get("/?<activityId>&<profileId>&<since>")]
+#[get("/?<activityId>&<profileId>&<profileIds>&<since>")]
 async fn get(
     activityId: &str,
     profileId: Option<&str>,
+    profileIds: Option<&str>,
     since: Option<&str>,
     db: &State<DB>,
     user: User,
@@ -278,7 +279,16 @@ async fn get(
             info: format!("No such Activity ({})", activity.id()).into(),
         }),
         Some(activity_id) => {
-            let resource = if let Some(z_profile_id) = profileId {
+            let resource = if let Some(z_profile_ids) = profileIds {
+                if profileId.is_some() || since.is_some() {
+                    return Err(MyError::HTTP {
+                        status: Status::BadRequest,
+                        info: "`profileIds` cannot be combined w/ `profileId` or `since`".into(),
+                    });
+                } else {
+                    get_profiles(conn, activity_id, z_profile_ids).await?
+                }
+            } else if let Some(z_profile_id) = profileId {
                 if since.is_some() {
                     return Err(MyError::HTTP {
                         status: Status::BadRequest,
@@ -315,6 +325,34 @@ async fn get_profile(
     }
 }
 
+/// Return a JSON object mapping each of the comma-separated `profile_ids` to
+/// its document, skipping unknown ones, along w/ the most recent update time.
+///
+/// Raise [MyError] w/ a 404 status if none of them is known.
+async fn get_profiles(
+    conn: &PgPool,
+    activity_id: i32,
+    profile_ids: &str,
+) -> Result<(String, DateTime<Utc>), MyError> {
+    let ids = split_profile_ids(profile_ids)?;
+    let (docs, last_updated) = find_many(conn, activity_id, &ids).await?;
+    if docs.is_empty() {
+        return Err(MyError::HTTP {
+            status: Status::NotFound,
+            info: format!(
+                "No profile found for activity ({activity_id}), and profiles ({profile_ids})"
+            )
+            .into(),
+        });
+    }
+
+    let map: Map<String, Value> = docs
+        .into_iter()
+        .map(|(id, doc)| (id, doc_as_json(doc)))
+        .collect();
+    Ok((serde_json::to_string(&map).unwrap(), last_updated))
+}
+
 async fn get_ids(
     conn: &PgPool,
     activity_id: i32,
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+#![allow(non_snake_case)]
+
+//! Administrative end-points not covered by the xAPI specification.
+//!
+//! * **`GET /admin/audit?statementId=<uuid>`** returns, in chronological
+//!   order, the audit trail of who inserted and/or voided a _Statement_. Only
+//!   _Root_ and _Admin_ users can access it. The trail is only populated when
+//!   **_LaRS_** is running in _User_ or _Auth_ mode.
+
+use crate::{
+    DataError, MyError,
+    db::audit::{AuditEntry, find_audit},
+    lrs::{DB, User},
+};
+use rocket::{Route, State, get, http::Status, routes, serde::json::Json};
+use tracing::debug;
+use uuid::Uuid;
+
+#[doc(hidden)]
+pub fn routes() -> Vec<Route> {
+    routes![get_audit]
+}
+
+/// Fetch the audit trail of a _Statement_ given its UUID.
+#[get("/audit?<statementId>")]
+async fn get_audit(
+    statementId: &str,
+    db: &State<DB>,
+    user: User,
+) -> Result<Json<Vec<AuditEntry>>, MyError> {
+    debug!("----- get_audit ----- {}", user);
+    user.can_manage_users()?;
+
+    let uuid = Uuid::parse_str(statementId)
+        .map_err(|x| MyError::Data(DataError::UUID(x)).with_status(Status::BadRequest))?;
+    let x = find_audit(db.pool(), &uuid).await?;
+    Ok(Json(x))
+}
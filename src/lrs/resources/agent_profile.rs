@@ -25,12 +25,12 @@ use crate::{
     DataError, MyError,
     db::{
         actor::find_agent_id_from_str,
-        agent_profile::{find, find_ids, remove, upsert},
+        agent_profile::{find, find_ids, find_many, remove, upsert},
     },
     eval_preconditions,
     lrs::{
         DB, Headers, User, WithDocumentOrIDs, emit_doc_response, etag_from_str, no_content,
-        resources::WithETag,
+        resources::{WithETag, doc_as_json, split_profile_ids},
     },
 };
 use chrono::{DateTime, Utc};
@@ -258,10 +258,11 @@ async fn delete(
 /// Otherwise, fetch IDs of all Agent Profile documents for the given Agent. If
 /// `since` is specified, then limit result to records that have been stored or
 /// updated since the specified Timestamp (exclusive).
-#[get("/?<agent>&<profileId>&<since>")]
+#[get("/?<agent>&<profileId>&<profileIds>&<since>")]
 async fn get(
     agent: &str,
     profileId: Option<&str>,
+    profileIds: Option<&str>,
     since: Option<&str>,
     db: &State<DB>,
     user: User,
@@ -273,7 +274,16 @@ async fn get(
     match find_agent_id_from_str(conn, agent).await {
         Ok(agent_id) => {
             debug!("agent_id = {}", agent_id);
-            let resource = if let Some(z_profile_id) = profileId {
+            let resource = if let Some(z_profile_ids) = profileIds {
+                if profileId.is_some() || since.is_some() {
+                    return Err(MyError::HTTP {
+                        status: Status::BadRequest,
+                        info: "`profileIds` cannot be combined w/ `profileId` or `since`".into(),
+                    });
+                } else {
+                    get_profiles(conn, agent_id, z_profile_ids).await?
+                }
+            } else if let Some(z_profile_id) = profileId {
                 if since.is_some() {
                     return Err(MyError::HTTP {
                         status: Status::BadRequest,
@@ -312,6 +322,32 @@ async fn get_profile(
     }
 }
 
+/// Return a JSON object mapping each of the comma-separated `profile_ids` to
+/// its document, skipping unknown ones, along w/ the most recent update time.
+///
+/// Raise [MyError] w/ a 404 status if none of them is known.
+async fn get_profiles(
+    conn: &PgPool,
+    actor_id: i32,
+    profile_ids: &str,
+) -> Result<(String, DateTime<Utc>), MyError> {
+    let ids = split_profile_ids(profile_ids)?;
+    let (docs, last_updated) = find_many(conn, actor_id, &ids).await?;
+    if docs.is_empty() {
+        return Err(MyError::HTTP {
+            status: Status::NotFound,
+            info: format!("Failed find Agent Profiles ({profile_ids}) for Actor #{actor_id}")
+                .into(),
+        });
+    }
+
+    let map: Map<String, Value> = docs
+        .into_iter()
+        .map(|(id, doc)| (id, doc_as_json(doc)))
+        .collect();
+    Ok((serde_json::to_string(&map).unwrap(), last_updated))
+}
+
 async fn get_ids(
     conn: &PgPool,
     actor_id: i32,
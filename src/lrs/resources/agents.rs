@@ -31,6 +31,8 @@ pub fn routes() -> Vec<rocket::Route> {
     routes![get]
 }
 
+// NOTE (rsn) 20261016 - `agent` is required. when missing Rocket forwards w/
+// a 422 which our catcher turns into a 400 (Bad Request).
 #[get("/?<agent>")]
 async fn get(
     c: Headers,
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Expose server counters in [Prometheus text format][1]. The metrics we
+//! maintain here are:
+//!
+//! * Total number of persisted Statements,
+//! * Total number of voided Statements,
+//! * Number of rejected Statement ingestion requests per reason, and
+//! * Number of requests per HTTP response status code.
+//!
+//! [1]: https://prometheus.io/docs/instrumenting/exposition_formats/
+
+use crate::{MyError, config, lrs::User};
+use dashmap::DashMap;
+use rocket::{
+    State, get,
+    http::{ContentType, Status},
+    routes,
+};
+use std::{
+    fmt::Write,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use tracing::debug;
+
+/// Why a request to store one or more Statements was rejected.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Rejection {
+    /// Malformed or invalid Statement(s).
+    Validation,
+    /// A different Statement w/ the same UUID is already stored.
+    Conflict,
+    /// Request pre-conditions were missing or not met.
+    Precondition,
+    /// Request body larger than the configured limit.
+    TooLarge,
+    /// Malformed `multipart/mixed` body; e.g. an Attachment part w/ missing
+    /// headers or one that doesn't match any of the Statements' Attachments.
+    Attachment,
+}
+
+impl Rejection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Rejection::Validation => "validation",
+            Rejection::Conflict => "conflict",
+            Rejection::Precondition => "precondition",
+            Rejection::TooLarge => "too_large",
+            Rejection::Attachment => "attachment",
+        }
+    }
+
+    /// Return the reason a request that failed w/ `x` was rejected, falling
+    /// back to `otherwise` when its status is not specific enough. Return
+    /// `None` if `x` is not the client's fault.
+    fn of(x: &MyError, otherwise: Rejection) -> Option<Rejection> {
+        match x {
+            MyError::HTTP { status, .. } => match status.code {
+                409 => Some(Rejection::Conflict),
+                412 | 428 => Some(Rejection::Precondition),
+                413 => Some(Rejection::TooLarge),
+                400..=499 => Some(otherwise),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The server's counters. Managed by Rocket as part of the server's state.
+#[derive(Debug, Default)]
+pub(crate) struct Counters {
+    statements: AtomicU64,
+    voided: AtomicU64,
+    rejected: DashMap<Rejection, AtomicU64>,
+    by_status: DashMap<u16, AtomicU64>,
+}
+
+impl Counters {
+    /// Add `n` to the count of persisted Statements.
+    pub(crate) fn statements(&self, n: usize) {
+        self.statements.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// Add `n` to the count of voided Statements.
+    pub(crate) fn voided(&self, n: usize) {
+        self.voided.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// Increment the count of responses sent w/ the given HTTP `status`.
+    pub(crate) fn status(&self, status: Status) {
+        self.by_status
+            .entry(status.code)
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count the Statement ingestion request that failed w/ `x` as rejected,
+    /// for the reason [Rejection::of] infers, before passing `x` on.
+    pub(crate) fn rejected(&self, x: MyError, otherwise: Rejection) -> MyError {
+        if let Some(reason) = Rejection::of(&x, otherwise) {
+            self.rejected
+                .entry(reason)
+                .or_default()
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        x
+    }
+
+    /// Return the counts of rejected requests sorted by reason.
+    fn rejections(&self) -> Vec<(Rejection, u64)> {
+        let mut res: Vec<_> = self
+            .rejected
+            .iter()
+            .map(|x| (*x.key(), x.value().load(Ordering::Relaxed)))
+            .collect();
+        res.sort_unstable();
+        res
+    }
+
+    /// Return the counts of responses sent sorted by status code.
+    fn statuses(&self) -> Vec<(u16, u64)> {
+        let mut res: Vec<_> = self
+            .by_status
+            .iter()
+            .map(|x| (*x.key(), x.value().load(Ordering::Relaxed)))
+            .collect();
+        res.sort_unstable();
+        res
+    }
+}
+
+#[doc(hidden)]
+pub fn routes() -> Vec<rocket::Route> {
+    routes![metrics]
+}
+
+#[get("/")]
+async fn metrics(counters: &State<Counters>, user: User) -> Result<(ContentType, String), MyError> {
+    debug!("----- metrics ----- {}", user);
+    if !config().metrics_enabled {
+        return Err(MyError::HTTP {
+            status: Status::NotFound,
+            info: "Metrics are disabled".into(),
+        });
+    }
+    user.can_view_metrics()?;
+
+    let mut out = String::new();
+    counter(
+        &mut out,
+        "statements_total",
+        "Total number of persisted Statements.",
+    );
+    let _ = writeln!(
+        out,
+        "statements_total {}",
+        counters.statements.load(Ordering::Relaxed)
+    );
+    counter(
+        &mut out,
+        "statements_voided_total",
+        "Total number of voided Statements.",
+    );
+    let _ = writeln!(
+        out,
+        "statements_voided_total {}",
+        counters.voided.load(Ordering::Relaxed)
+    );
+    counter(
+        &mut out,
+        "statements_rejected_total",
+        "Number of rejected Statement ingestion requests per reason.",
+    );
+    for (reason, n) in counters.rejections() {
+        let _ = writeln!(
+            out,
+            "statements_rejected_total{{reason=\"{}\"}} {n}",
+            reason.as_str()
+        );
+    }
+    counter(
+        &mut out,
+        "requests_by_status_total",
+        "Number of requests per response status code.",
+    );
+    for (code, n) in counters.statuses() {
+        let _ = writeln!(out, "requests_by_status_total{{status=\"{code}\"}} {n}");
+    }
+
+    let ct = ContentType::new("text", "plain").with_params(("version", "0.0.4"));
+    Ok((ct, out))
+}
+
+/// Write the `HELP` and `TYPE` comment lines of a counter named `name`.
+fn counter(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} counter");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters() {
+        let counters = Counters::default();
+
+        counters.statements(3);
+        counters.voided(1);
+        counters.status(Status::Ok);
+        counters.status(Status::Ok);
+        counters.status(Status::BadRequest);
+        let bad_request = MyError::HTTP {
+            status: Status::BadRequest,
+            info: "Invalid Statement".into(),
+        };
+        let _ = counters.rejected(bad_request, Rejection::Validation);
+        let conflict = MyError::HTTP {
+            status: Status::Conflict,
+            info: "Different Statement w/ same UUID".into(),
+        };
+        let _ = counters.rejected(conflict, Rejection::Validation);
+        // server errors are not the client's fault...
+        let internal = MyError::HTTP {
+            status: Status::InternalServerError,
+            info: "Oops".into(),
+        };
+        let _ = counters.rejected(internal, Rejection::Validation);
+
+        assert_eq!(counters.statements.load(Ordering::Relaxed), 3);
+        assert_eq!(counters.voided.load(Ordering::Relaxed), 1);
+        assert_eq!(counters.statuses(), vec![(200, 2), (400, 1)]);
+        assert_eq!(
+            counters.rejections(),
+            vec![(Rejection::Validation, 1), (Rejection::Conflict, 1)]
+        );
+    }
+}
@@ -5,8 +5,10 @@
 pub mod about;
 pub mod activities;
 pub mod activity_profile;
+pub mod admin;
 pub mod agent_profile;
 pub mod agents;
+pub mod metrics;
 pub mod state;
 pub mod statement;
 pub mod stats;
@@ -14,7 +16,7 @@ pub mod users;
 pub mod verbs;
 
 use crate::{
-    DataError, MyError,
+    DataError, MyError, config,
     lrs::{Headers, server::get_consistent_thru},
 };
 use chrono::{DateTime, SecondsFormat, Utc};
@@ -25,6 +27,7 @@ use rocket::{
     serde::json::Json,
 };
 use serde::Serialize;
+use serde_json::Value;
 use tracing::debug;
 
 /// A derived Rocket Responder structure w/ an OK Status, a body consisting
@@ -38,12 +41,16 @@ pub(crate) struct WithResource<T> {
     last_modified: Header<'static>,
 }
 
+/// A derived Rocket Responder structure w/ an OK Status, a body consisting
+/// of a Document or a JSON array of IDs, and `Etag`, `Last-Modified` and
+/// `Cache-Control` Headers.
 #[derive(Responder)]
 #[response(status = 200, content_type = "json")]
 pub(crate) struct WithDocumentOrIDs {
     inner: String,
     etag: Header<'static>,
     last_modified: Header<'static>,
+    cache_control: Header<'static>,
 }
 
 /// A derived Rocket Responder w/ a No Content Status and an ETag Header only.
@@ -53,6 +60,37 @@ pub(crate) struct WithETag {
     etag: Header<'static>,
 }
 
+/// Maximum number of identifiers accepted in a `profileIds` query parameter.
+pub(crate) const MAX_PROFILE_IDS: usize = 20;
+
+/// Split the value of a `profileIds` query parameter --a comma-separated list
+/// of profile identifiers-- into a collection of distinct non-empty ones.
+///
+/// Raise [MyError] w/ a 400 status if there are none or more than
+/// [MAX_PROFILE_IDS] of them.
+pub(crate) fn split_profile_ids(val: &str) -> Result<Vec<&str>, MyError> {
+    let mut result: Vec<&str> = vec![];
+    for id in val.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+        if !result.contains(&id) {
+            result.push(id)
+        }
+    }
+    if result.is_empty() || result.len() > MAX_PROFILE_IDS {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("`profileIds` must contain 1 to {MAX_PROFILE_IDS} identifiers").into(),
+        });
+    }
+
+    Ok(result)
+}
+
+/// Return a stored profile document as a JSON value; i.e. as is if it's valid
+/// JSON or as a JSON string otherwise.
+pub(crate) fn doc_as_json(doc: String) -> Value {
+    serde_json::from_str::<Value>(&doc).unwrap_or(Value::String(doc))
+}
+
 /// Given a string reference `s`, hash its bytes and return an `EntityTag`
 /// instance built from the resulting hash.
 pub(crate) fn etag_from_str(s: &str) -> EntityTag {
@@ -153,6 +191,8 @@ macro_rules! emit_response {
 /// The `timestamp` argument will be used to populate the `Last-Modified`
 /// header. If it's `None` the value of the CONSISTENT_THRU Singleton will
 /// be used.
+///
+/// The `Cache-Control` header is set to the configured `doc_cache_control`.
 pub(crate) async fn emit_doc_response(
     resource: String,
     timestamp: Option<DateTime<Utc>>,
@@ -171,6 +211,10 @@ pub(crate) async fn emit_doc_response(
         inner: resource,
         etag: Header::new(header::ETAG.as_str(), etag.to_string()),
         last_modified: Header::new(header::LAST_MODIFIED.as_str(), last_modified),
+        cache_control: Header::new(
+            header::CACHE_CONTROL.as_str(),
+            config().doc_cache_control.clone(),
+        ),
     })
 }
 
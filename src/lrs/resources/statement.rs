@@ -14,39 +14,49 @@
 //! [1]: https://opensource.ieee.org/xapi/xapi-base-standard-documentation/-/blob/main/9274.1.xAPI%20Base%20Standard%20for%20LRSs.md#4161-statement-resource-statements
 
 use crate::{
-    DataError, MyError, config,
-    data::{Actor, Attachment, Format, Statement, StatementIDs, statement_type::StatementType},
+    DataError, Mode, MyError, config,
+    data::{
+        Actor, Attachment, Format, Statement, StatementIDs, ValidationError, check_duplicate_keys,
+        statement_type::StatementType,
+    },
     db::{
-        filter::{Filter, register_new_filter},
+        filter::{DEFAULT_ASCENDING, Filter, drop_filter, register_new_filter},
         statement::{
-            find_more_statements, find_statement_by_uuid, find_statement_to_void,
-            find_statements_by_filter, insert_statement, statement_exists, void_statement,
+            PagingInfo, find_more_statements, find_statement_by_uuid, find_statement_to_void,
+            find_statements_by_filter, find_statements_slice, insert_statement, insert_statements,
+            prepare_filter_view, statement_exists, void_statements,
         },
     },
     emit_response, eval_preconditions,
     lrs::{
         DB, Signature, User, compute_etag,
         headers::{CONSISTENT_THRU_HDR, CONTENT_TRANSFER_ENCODING_HDR, HASH_HDR, Headers},
-        resources::{WithETag, WithResource},
-        server::{get_consistent_thru, qp},
+        resources::{
+            WithETag, WithResource,
+            metrics::{Counters, Rejection},
+        },
+        server::{get_consistent_thru, qp, strict_qp},
     },
 };
 use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
 use chrono::{DateTime, SecondsFormat, Utc};
 use mime::{APPLICATION_JSON, Mime};
-use openssl::sha::Sha256;
+use openssl::{
+    hash::{Hasher, MessageDigest},
+    sha::Sha256,
+};
 use rocket::{
     Request, Responder, State,
+    data::{Capped, Data, Limits, ToByteUnit},
     futures::{Stream, TryFutureExt},
     get,
     http::{ContentType, Header, Status, hyper::header},
     post, put,
     request::{FromRequest, Outcome},
-    response::stream::stream,
+    response::stream::{TextStream, stream},
     routes,
-    serde::json::Json,
     tokio::{
-        fs::{DirBuilder, File},
+        fs::{self, DirBuilder, File, OpenOptions},
         io::{AsyncReadExt, AsyncWriteExt},
     },
 };
@@ -55,7 +65,14 @@ use serde::{Deserialize, de::DeserializeOwned};
 use serde_json::{Map, Value};
 use serde_with::serde_as;
 use sqlx::PgPool;
-use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    io::ErrorKind,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Mutex, MutexGuard, OnceLock},
+    time::Duration,
+};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
@@ -74,11 +91,13 @@ struct PostResponse {
 }
 
 /// A derived Rocket Responder structure w/ an OK Status, a body consisting
-/// of the JSON Serialized string of a generic type `T`, an `Etag` and
-/// `Last-Modified` Headers.  The Type to serialize here is [Statement].
+/// of the JSON Serialized string of a generic type `T`, an `Etag`,
+/// `Last-Modified` and `Consistent-Through` Headers.  The Type to serialize
+/// here is [Statement].
 #[derive(Responder)]
 struct GetResponse {
     inner: WithResource<StatementType>,
+    consistent_thru: Header<'static>,
 }
 
 /// General purpose Rocket Responder to use w/ `GET` Requests to cater for the
@@ -90,6 +109,73 @@ enum EitherOr<T> {
     Mixed(MultipartStream<T>),
 }
 
+/// A derived Rocket Responder structure w/ an OK Status, a body consisting of
+/// a StatementResult JSON object streamed incrementally, and a `Consistent-Through`
+/// xAPI header.
+#[derive(Responder)]
+#[response(content_type = "json")]
+struct StreamResponse<S> {
+    inner: TextStream<S>,
+    consistent_thru: Header<'static>,
+}
+
+/// A derived Rocket Responder w/ the Status of an Attachment upload request
+/// and a `Range` Header reflecting the bytes received so far.
+#[derive(Responder)]
+struct UploadResponse {
+    inner: Status,
+    range: Header<'static>,
+}
+
+impl UploadResponse {
+    fn new(status: Status, received: u64) -> Self {
+        UploadResponse {
+            inner: status,
+            range: Header::new(
+                header::RANGE.as_str(),
+                format!("bytes=0-{}", received.saturating_sub(1)),
+            ),
+        }
+    }
+}
+
+/// The `first`, `last` and `complete-length` values of a `Content-Range`
+/// request header of the form `bytes <first>-<last>/<complete-length>`,
+/// or `None` if the header is absent.
+struct ContentRange(Option<(u64, u64, u64)>);
+
+impl ContentRange {
+    fn parse(val: &str) -> Option<(u64, u64, u64)> {
+        let (range, total) = val.trim().strip_prefix("bytes ")?.split_once('/')?;
+        let (first, last) = range.split_once('-')?;
+        let first = first.parse().ok()?;
+        let last = last.parse().ok()?;
+        let total = total.parse().ok()?;
+        (first <= last && last < total).then_some((first, last, total))
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ContentRange {
+    type Error = MyError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match req.headers().get_one(header::CONTENT_RANGE.as_str()) {
+            None => Outcome::Success(ContentRange(None)),
+            Some(x) => match ContentRange::parse(x) {
+                Some(r) => Outcome::Success(ContentRange(Some(r))),
+                None => Outcome::Error((
+                    Status::BadRequest,
+                    MyError::HTTP {
+                        status: Status::BadRequest,
+                        info: format!("Malformed Content-Range: '{x}'").into(),
+                    },
+                )),
+            },
+        }
+    }
+}
+
 /// Construct a file-name from an Attachment hash signature. A file w/ that
 /// name will be created and stored under the `static` folder.
 fn sha2_path(sha2: &str) -> PathBuf {
@@ -101,6 +187,118 @@ fn sha2_path(sha2: &str) -> PathBuf {
     config().static_dir.join(format!("_{name}"))
 }
 
+// paths of the Attachments w/ a chunk being written by [put_attachment].
+static UPLOADING: OnceLock<Mutex<HashSet<PathBuf>>> = OnceLock::new();
+fn uploading() -> &'static Mutex<HashSet<PathBuf>> {
+    UPLOADING.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Exclusive right to write the contents of the Attachment at a given path;
+/// released when dropped.
+struct UploadGuard(PathBuf);
+
+impl UploadGuard {
+    /// Return `None` if another request is already writing to `path`.
+    fn acquire(path: &PathBuf) -> Option<Self> {
+        let mut paths = uploading().lock().expect("Failed locking uploads");
+        paths
+            .insert(path.to_owned())
+            .then(|| UploadGuard(path.to_owned()))
+    }
+}
+
+impl Drop for UploadGuard {
+    fn drop(&mut self) {
+        let mut paths = uploading().lock().expect("Failed locking uploads");
+        paths.remove(&self.0);
+    }
+}
+
+// running digests of the bytes received so far by [put_attachment], keyed by
+// the path of the Attachment they're for.
+static DIGESTS: OnceLock<Mutex<HashMap<PathBuf, Hasher>>> = OnceLock::new();
+fn digests() -> Result<MutexGuard<'static, HashMap<PathBuf, Hasher>>, MyError> {
+    DIGESTS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .map_err(|x| MyError::Runtime(format!("Failed locking digests: {x}").into()))
+}
+
+/// Return the running digest of the `received` bytes already appended to the
+/// `partial` contents of the Attachment at `path`. When missing (e.g. after a
+/// restart or a failed chunk) it is rebuilt by reading `partial` one block at
+/// a time.
+async fn running_digest(
+    path: &PathBuf,
+    partial: &PathBuf,
+    sha2: &str,
+    received: u64,
+) -> Result<Hasher, MyError> {
+    let cached = digests()?.remove(path);
+    if received > 0
+        && let Some(x) = cached
+    {
+        return Ok(x);
+    }
+
+    let md = match sha2.len() {
+        56 => MessageDigest::sha224(),
+        64 => MessageDigest::sha256(),
+        96 => MessageDigest::sha384(),
+        _ => MessageDigest::sha512(),
+    };
+    let mut result = Hasher::new(md)?;
+    if received > 0 {
+        let mut file = File::open(partial).map_err(MyError::IO).await?;
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let n = file.read(&mut buf).map_err(MyError::IO).await?;
+            if n == 0 {
+                break;
+            }
+            result.update(&buf[..n])?;
+        }
+    }
+    Ok(result)
+}
+
+/// Discard partially uploaded Attachments that received no chunk in the last
+/// UPLOAD_TTL_SECS seconds.
+pub(crate) fn clean_partial_uploads() -> Result<(), MyError> {
+    let ttl = Duration::from_secs(config().upload_ttl);
+    let objects = match std::fs::read_dir(&config().static_dir) {
+        Ok(x) => x,
+        Err(x) if x.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(x) => return Err(MyError::IO(x)),
+    };
+    for obj in objects {
+        let path = obj?.path();
+        if path.extension().is_none_or(|x| x != "part") {
+            continue;
+        }
+        let stale = std::fs::metadata(&path)?
+            .modified()?
+            .elapsed()
+            .is_ok_and(|x| x > ttl);
+        if stale && let Some(_guard) = UploadGuard::acquire(&path.with_extension("")) {
+            info!("Discarding stale partial upload {:?}", path);
+            std::fs::remove_file(&path)?;
+            digests()?.remove(&path.with_extension(""));
+        }
+    }
+    Ok(())
+}
+
+/// Return TRUE if `att` has no `fileUrl` and its contents were not previously
+/// uploaded through [put_attachment]. A stored file whose size differs from
+/// the Attachment's declared `length` does not count as its contents.
+fn is_unresolved(att: &Attachment) -> bool {
+    att.file_url().is_none()
+        && !(config().resumable_uploads
+            && std::fs::metadata(sha2_path(att.sha2()))
+                .is_ok_and(|x| i64::try_from(x.len()).is_ok_and(|n| n == att.length())))
+}
+
 /// Captures information about a potential Attachment w/in a multipart/mixed
 /// Request.
 #[derive(Debug, PartialEq)]
@@ -152,7 +350,7 @@ struct QueryParams<'a> {
 
 #[rocket::async_trait]
 impl<'r> FromRequest<'r> for QueryParams<'r> {
-    type Error = ();
+    type Error = MyError;
 
     async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
         let statement_id = qp::<&str>(req, "statementId");
@@ -164,7 +362,12 @@ impl<'r> FromRequest<'r> for QueryParams<'r> {
         let since = qp::<&str>(req, "since");
         let until = qp::<&str>(req, "until");
 
-        let limit = qp::<u32>(req, "limit");
+        // NOTE (rsn) 20261016 - a `limit` that can't be parsed is an error
+        // rather than an absent one...
+        let limit = match strict_qp::<u32>(req, "limit") {
+            Ok(x) => x,
+            Err(x) => return Outcome::Error((Status::BadRequest, x)),
+        };
 
         let related_activities = qp::<bool>(req, "related_activities");
         let related_agents = qp::<bool>(req, "related_agents");
@@ -237,7 +440,16 @@ impl OutPartInfo {
 #[doc(hidden)]
 pub fn routes() -> Vec<rocket::Route> {
     routes![
-        put_mixed, put_json, post_mixed, post_json, __post, post_form, get_some, get_more
+        put_mixed,
+        put_json,
+        post_mixed,
+        post_json,
+        __post,
+        post_form,
+        get_some,
+        get_more,
+        get_stream,
+        put_attachment
     ]
 }
 
@@ -265,78 +477,56 @@ async fn put_mixed(
     statementId: &str,
     data: MultipartReader<'_>,
     db: &State<DB>,
+    counters: &State<Counters>,
     user: User,
 ) -> Result<PutResponse, MyError> {
     debug!("----- put_mixed ----- {}", user);
     user.can_use_xapi()?;
 
-    let uuid = Uuid::parse_str(statementId)
-        .map_err(|x| MyError::Data(DataError::UUID(x)).with_status(Status::BadRequest))?;
+    let uuid = Uuid::parse_str(statementId).map_err(|x| {
+        let x = MyError::Data(DataError::UUID(x)).with_status(Status::BadRequest);
+        counters.rejected(x, Rejection::Validation)
+    })?;
     debug!("Statement UUID = {}", uuid);
 
     // we use this here for a single Statement as w/ POST for multiple ones
     // to locally store included attachments' data if any.
-    let mut statements = ingest_multipart(data, false).await?;
+    let mut statements = ingest_multipart(data, false)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Attachment))?;
 
     let statement = statements.iter_mut().next().unwrap();
-    if statement.id().is_none() {
-        statement.set_id(uuid)
-    } else if *statement.id().unwrap() != uuid {
-        return Err(MyError::HTTP {
-            status: Status::BadRequest,
-            info: "Statement ID in URL does not match one in body".into(),
-        });
-    }
-
-    return persist_one(db.pool(), c, statement, &user).await;
+    persist_one(db.pool(), counters, c, uuid, statement, &user)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Validation))
 }
 
-#[put("/?<statementId>", data = "<json>", format = "application/json")]
+#[put("/?<statementId>", data = "<data>", format = "application/json")]
 async fn put_json(
     c: Headers,
     statementId: &str,
-    json: &str,
+    data: Data<'_>,
     db: &State<DB>,
+    counters: &State<Counters>,
     user: User,
 ) -> Result<PutResponse, MyError> {
     debug!("----- put_json ----- {}", user);
     user.can_use_xapi()?;
 
-    let uuid = Uuid::parse_str(statementId)
-        .map_err(|x| MyError::Data(DataError::UUID(x)).with_status(Status::BadRequest))?;
+    let uuid = Uuid::parse_str(statementId).map_err(|x| {
+        let x = MyError::Data(DataError::UUID(x)).with_status(Status::BadRequest);
+        counters.rejected(x, Rejection::Validation)
+    })?;
     debug!("statement UUID = {}", uuid);
 
+    let json = read_json(data)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Validation))?;
     let mut statement =
-        Statement::from_str(json).map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
-
-    // NOTE (rsn) 202410004 /4.1.3 Content Types/ - When receiving a PUT or
-    // POST request with application/json content-type, an LRS shall respond
-    // w/ HTTP 400 Bad Request if, when present, Attachment objects in the
-    // Statement(s) do not have populated fileUrl property.
-    let mut count = 0;
-    for att in statement.attachments() {
-        if att.file_url().is_none() {
-            count += 1;
-        }
-    }
-    if count > 0 {
-        error!("Found {} Attachment(s) w/ unpopulated 'fileUrl'", count);
-        return Err(MyError::HTTP {
-            status: Status::BadRequest,
-            info: format!("Found {count} Attachment(s) w/ unpopulated 'fileUrl'").into(),
-        });
-    }
-
-    if statement.id().is_none() {
-        statement.set_id(uuid)
-    } else if *statement.id().unwrap() != uuid {
-        return Err(MyError::HTTP {
-            status: Status::BadRequest,
-            info: "Statement ID in URL does not match one in body".into(),
-        });
-    }
-
-    return persist_one(db.pool(), c, &mut statement, &user).await;
+        statement_from_json(json).map_err(|x| counters.rejected(x, Rejection::Validation))?;
+    persist_one(db.pool(), counters, c, uuid, &mut statement, &user)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Validation))
 }
 
 /// From section 4.1.6.1 Statement Resource (/statements) [POST Request][1]:
@@ -365,55 +555,45 @@ async fn post_mixed(
     c: Headers,
     data: MultipartReader<'_>,
     db: &State<DB>,
+    counters: &State<Counters>,
     user: User,
 ) -> Result<PostResponse, MyError> {
     debug!("----- post_mixed ----- {}", user);
     user.can_use_xapi()?;
 
     debug!("c = {:?}", c);
-    let statements = ingest_multipart(data, true).await?;
+    let statements = ingest_multipart(data, true)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Attachment))?;
 
-    persist_many(db.pool(), c, statements, &user).await
+    persist_many(db.pool(), counters, c, statements, &user)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Validation))
 }
 
-#[post("/", data = "<json>", format = "application/json")]
+// NOTE (rsn) 20261016 - Rocket matches a route's `format` against the request's
+// Content-Type top-level and sub-level types only, ignoring parameters.  so
+// `application/json; charset=utf-8` is routed here too.
+#[post("/", data = "<data>", format = "application/json")]
 async fn post_json(
     c: Headers,
-    json: Json<Statements>,
+    data: Data<'_>,
     db: &State<DB>,
+    counters: &State<Counters>,
     user: User,
 ) -> Result<PostResponse, MyError> {
     debug!("----- post_json ----- {}", user);
     user.can_use_xapi()?;
 
     debug!("c = {:?}", c);
-    let mut statements = vec![];
-    for map in json.0.0 {
-        let x = Statement::from_json_obj(map)
-            .map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
-        statements.push(x)
-    }
-
-    // NOTE (rsn) 202410004 /4.1.3 Content Types/ - When receiving a PUT or
-    // POST request with application/json content-type, an LRS shall respond
-    // w/ HTTP 400 Bad Request if, when present, Attachment objects in the
-    // Statement(s) do not have populated fileUrl property.
-    let mut count = 0;
-    for s in &statements {
-        for att in s.attachments() {
-            if att.file_url().is_none() {
-                count += 1;
-            }
-        }
-    }
-    if count > 0 {
-        return Err(MyError::HTTP {
-            status: Status::BadRequest,
-            info: format!("Statement w/ {count} unresolved Attachment(s)").into(),
-        });
-    }
-
-    persist_many(db.pool(), c, statements, &user).await
+    let json = read_json(data)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Validation))?;
+    let statements =
+        statements_from_json(json).map_err(|x| counters.rejected(x, Rejection::Validation))?;
+    persist_many(db.pool(), counters, c, statements, &user)
+        .await
+        .map_err(|x| counters.rejected(x, Rejection::Validation))
 }
 
 // IMPORTANT (rsn) 20241111 - CTS runs show that requests w/ malformed CT headers
@@ -438,6 +618,144 @@ async fn post_form() -> Result<PostResponse, MyError> {
     })
 }
 
+/// Upload (part of) the contents of an Attachment identified by its `sha2`
+/// hash ahead of sending the Statement(s) referencing it w/ an
+/// `application/json` content type.
+///
+/// Each request carries a chunk of those contents described by a
+/// `Content-Range` header; e.g. `bytes 0-1023/4096`. When absent, the body is
+/// deemed to be the whole contents. Chunks are appended, in order, to a partial
+/// file. Once all bytes are received, their hash is checked against `sha2` and
+/// if they match, the contents become available to Attachments w/o a `fileUrl`.
+///
+/// Respond w/ 202 (Accepted) while more bytes are expected, or 204 (No Content)
+/// once the upload is complete. In both cases a `Range` header reflects the
+/// bytes received so far. A chunk that doesn't start where the previous one
+/// ended, or that arrives while another chunk for the same `sha2` is being
+/// written, is rejected w/ a 409 (Conflict). Contents larger than the
+/// configured MULTIPART_LIMIT are rejected w/ a 413 (Payload Too Large), and
+/// partial ones left alone for UPLOAD_TTL_SECS are discarded.
+#[put("/attachments/<sha2>", data = "<data>")]
+async fn put_attachment(
+    sha2: &str,
+    range: ContentRange,
+    data: Data<'_>,
+    limits: &Limits,
+    user: User,
+) -> Result<UploadResponse, MyError> {
+    debug!("----- put_attachment ----- {}", user);
+    if !config().resumable_uploads {
+        return Err(MyError::HTTP {
+            status: Status::NotFound,
+            info: "Resumable uploads are disabled".into(),
+        });
+    }
+    user.can_use_xapi()?;
+
+    if hex::decode(sha2).is_err() || ![56, 64, 96, 128].contains(&sha2.len()) {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("Invalid SHA-2 hash: '{sha2}'").into(),
+        });
+    }
+
+    let path = sha2_path(sha2);
+    if path.exists() {
+        info!("Attachment {} already exists", sha2);
+        let len = fs::metadata(&path).await.map_err(MyError::IO)?.len();
+        return Ok(UploadResponse::new(Status::NoContent, len));
+    }
+
+    let limit = limits.get("file/multipart").unwrap_or(10.mebibytes());
+    let bytes = data.open(limit).into_bytes().await.map_err(MyError::IO)?;
+    if !bytes.is_complete() {
+        return Err(MyError::HTTP {
+            status: Status::PayloadTooLarge,
+            info: format!("Chunk exceeds {limit}").into(),
+        });
+    }
+    let bytes = bytes.into_inner();
+    let len = bytes.len() as u64;
+    let (first, last, total) = match range.0 {
+        Some(x) => x,
+        None if len > 0 => (0, len - 1, len),
+        None => {
+            return Err(MyError::HTTP {
+                status: Status::BadRequest,
+                info: "Empty Attachment upload".into(),
+            });
+        }
+    };
+    let span = last - first + 1;
+    if span != len {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("Content-Range spans {span} bytes but got {len}").into(),
+        });
+    }
+    if total > limit.as_u64() {
+        return Err(MyError::HTTP {
+            status: Status::PayloadTooLarge,
+            info: format!("Attachment exceeds {limit}").into(),
+        });
+    }
+
+    let Some(_guard) = UploadGuard::acquire(&path) else {
+        return Err(MyError::HTTP {
+            status: Status::Conflict,
+            info: format!("Another chunk of {sha2} is being uploaded").into(),
+        });
+    };
+    let partial = path.with_extension("part");
+    let received = match fs::metadata(&partial).await {
+        Ok(x) => x.len(),
+        Err(_) => 0,
+    };
+    if first != received {
+        return Err(MyError::HTTP {
+            status: Status::Conflict,
+            info: format!("Expected chunk starting at byte {received}").into(),
+        });
+    }
+
+    let mut digest = running_digest(&path, &partial, sha2, received).await?;
+    DirBuilder::new()
+        .recursive(true)
+        .create(path.parent().unwrap())
+        .map_err(MyError::IO)
+        .await?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&partial)
+        .map_err(MyError::IO)
+        .await?;
+    file.write_all(&bytes).map_err(MyError::IO).await?;
+    file.flush().map_err(MyError::IO).await?;
+    digest.update(&bytes)?;
+
+    let received = last + 1;
+    if received < total {
+        debug!("Received {} of {} bytes of {}", received, total, sha2);
+        digests()?.insert(path, digest);
+        return Ok(UploadResponse::new(Status::Accepted, received));
+    }
+
+    // all bytes are in; verify them before making them available...
+    let digest = hex::encode(&*digest.finish()?);
+    if !digest.eq_ignore_ascii_case(sha2) {
+        let _ = fs::remove_file(&partial).await;
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("Uploaded contents do not match hash '{sha2}'").into(),
+        });
+    }
+    fs::rename(&partial, &path).map_err(MyError::IO).await?;
+    info!("Completed upload of Attachment {}", sha2);
+
+    Ok(UploadResponse::new(Status::NoContent, received))
+}
+
 const VALID_GET_PARAMS: [&str; 14] = [
     "statementId",
     "voidedStatementId",
@@ -548,7 +866,9 @@ async fn get_some<'r>(
             q.related_agents,
             q.since,
             q.until,
-            q.limit,
+            // an explicit `limit`, incl. 0 (server's maximum), takes
+            // precedence over a page size header...
+            q.limit.or(c.page_size()),
             q.ascending,
         )
         .await
@@ -561,8 +881,12 @@ async fn get_some<'r>(
     debug!("resource = {:?}", resource);
     if !with_attachments {
         let stored = resource.stored();
+        let consistent_thru = consistent_through(effective_consistent_through(&resource).await);
         let x = emit_response!(c, resource => StatementType, stored)?;
-        Ok(EitherOr::JsonX(Box::new(GetResponse { inner: x })))
+        Ok(EitherOr::JsonX(Box::new(GetResponse {
+            inner: x,
+            consistent_thru,
+        })))
     } else {
         send_multipart(&resource).await
     }
@@ -571,11 +895,8 @@ async fn get_some<'r>(
 async fn send_multipart(
     resource: &StatementType,
 ) -> Result<EitherOr<impl Stream<Item = MultipartSection<'static>> + use<>>, MyError> {
-    let mut server_last_modified = get_consistent_thru().await;
+    let server_last_modified = effective_consistent_through(resource).await;
     let stored = resource.stored();
-    if stored > server_last_modified {
-        server_last_modified = stored
-    }
 
     let first_part = save_statements(resource).await?;
     let mut parts = vec![];
@@ -600,7 +921,139 @@ async fn send_multipart(
     })))
 }
 
-#[get("/more?<sid>&<count>&<offset>&<limit>&<format>&<attachments>")]
+/// LaRS specific variant of `GET /statements` that returns **all** the
+/// Statements matching the same filter parameters --or at most `limit` of
+/// them when specified-- in one StatementResult w/o a `more` property.
+///
+/// Instead of materializing the whole result before serializing it, the JSON
+/// array of Statements is written to the Response incrementally, fetching them
+/// from the database in batches of `DB_STATEMENTS_PAGE_LEN`. For this reason
+/// the Response has no `Etag` nor `Last-Modified` headers, and fetching a
+/// single Statement or its Attachments is not supported.
+///
+/// Note that once streaming starts, the Response status can no longer change.
+/// An error occurring mid-stream is logged, ends the `statements` array, and
+/// adds an `error` property to the StatementResult. Clients should treat such
+/// a result as incomplete.
+#[get("/stream?<extras..>")]
+async fn get_stream<'r>(
+    c: Headers,
+    q: QueryParams<'_>,
+    mut extras: HashMap<&'r str, &'r str>,
+    db: &State<DB>,
+    user: User,
+) -> Result<StreamResponse<impl Stream<Item = String> + use<>>, MyError> {
+    debug!("----- get_stream ----- {}", user);
+    user.can_use_xapi()?;
+
+    debug!("q = {:?}", q);
+    extras.retain(|k, _| !VALID_GET_PARAMS.contains(k));
+    if !extras.is_empty() {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("Received extraneous query string parameters: {extras:?}").into(),
+        });
+    }
+
+    if q.statement_id.is_some() || q.voided_statement_id.is_some() || q.attachments == Some(true) {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: "Streaming single Statements or Attachments is not supported".into(),
+        });
+    }
+
+    let format = Format::new(q.format.unwrap_or("exact"), c.languages().to_vec())
+        .map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
+    // a missing or zero `limit` means no limit...
+    let total = match q.limit {
+        Some(x) if x > 0 => i32::try_from(x).unwrap_or(i32::MAX),
+        _ => i32::MAX,
+    };
+
+    let conn = db.pool().clone();
+    let filter = Filter::from(
+        &conn,
+        q.agent,
+        q.verb,
+        q.activity,
+        q.registration,
+        q.related_activities,
+        q.related_agents,
+        q.since,
+        q.until,
+        q.limit,
+        q.ascending,
+    )
+    .await
+    .map_err(|x| x.with_status(Status::BadRequest))?;
+
+    let sid = register_new_filter(&conn).await?;
+    debug!("sid = {}", sid);
+    prepare_filter_view(&conn, &filter, sid).await?;
+    let ascending = filter.ascending();
+
+    let batch_len = config().db_statements_page_len;
+    let inner = TextStream(stream! {
+        yield r#"{"statements":["#.to_owned();
+        let mut after = None;
+        let mut sent = 0;
+        let mut first = true;
+        let mut failure = None;
+        while sent < total {
+            let limit = batch_len.min(total - sent);
+            let (slice, last) =
+                match find_statements_slice(&conn, sid, after, limit, ascending, &format).await {
+                    Ok(x) => x,
+                    Err(x) => {
+                        error!("Failed fetching Statements to stream: {}", x);
+                        failure = Some("Failed fetching Statements");
+                        break;
+                    }
+                };
+            let items = match slice.items_as_json() {
+                Ok(x) => x,
+                Err(x) => {
+                    error!("Failed serializing Statements to stream: {}", x);
+                    failure = Some("Failed serializing Statements");
+                    break;
+                }
+            };
+            let count = i32::try_from(items.len()).unwrap_or(i32::MAX);
+            for item in items {
+                if first {
+                    first = false;
+                } else {
+                    yield ",".to_owned();
+                }
+                yield item;
+            }
+            if count < limit {
+                break;
+            }
+            sent += limit;
+            after = last;
+        }
+        // NOTE (rsn) 20261016 - a client disconnecting mid-stream drops this
+        // generator before we get here.  the filter is then dropped w/ the
+        // stale ones...
+        drop_filter(&conn, sid).await;
+        // NOTE (rsn) 20261016 - the status line and headers are gone by now.
+        // on failure, flag the result as such so clients can't mistake a
+        // partial result for a complete one...
+        let end = match failure {
+            None => "]}".to_owned(),
+            Some(x) => format!(r#"],"error":"{x}"}}"#),
+        };
+        yield end;
+    });
+
+    Ok(StreamResponse {
+        inner,
+        consistent_thru: consistent_through(get_consistent_thru().await),
+    })
+}
+
+#[get("/more?<sid>&<count>&<offset>&<limit>&<format>&<attachments>&<ascending>")]
 async fn get_more(
     c: Headers,
     sid: u64,
@@ -609,6 +1062,7 @@ async fn get_more(
     limit: i32,
     format: &str,
     attachments: bool,
+    ascending: Option<bool>,
     db: &State<DB>,
     user: User,
 ) -> Result<EitherOr<impl Stream<Item = MultipartSection<'static>> + use<>>, MyError> {
@@ -622,38 +1076,30 @@ async fn get_more(
     debug!("limit = {}", limit);
     debug!("format = {}", format);
     debug!("attachments? {}", attachments);
+    // `more` URLs issued before `ascending` was added to them assume the
+    // default...
+    let ascending = ascending.unwrap_or(DEFAULT_ASCENDING);
+    debug!("ascending? {}", ascending);
 
     let format = Format::new(format, c.languages().to_vec())
         .map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
 
     let (mut resource, y) =
-        find_more_statements(db.pool(), sid, count, offset, limit, &format).await?;
+        find_more_statements(db.pool(), sid, count, offset, limit, ascending, &format).await?;
     if let Some(pi) = y {
-        let more = format!(
-            "statements/more/?sid={}&count={}&offset={}&limit={}&format={}&attachments={}",
-            sid,
-            pi.count,
-            pi.offset,
-            pi.limit,
-            format.as_param(),
-            attachments
-        );
-        let url = config().to_external_url(&more);
-        debug!("more URL = '{}'", url);
-        if let Err(z) = &resource.set_more(&url) {
-            warn!(
-                "Failed updating `more` URL of StatementResult. Ignore + continue but StatementResult will be inaccurate: {}",
-                z
-            );
-        }
+        set_more_url(&mut resource, sid, &pi, &format, attachments)?;
     }
 
     if attachments {
         send_multipart(&resource).await
     } else {
         let last_modified = get_consistent_thru().await;
+        let consistent_thru = consistent_through(effective_consistent_through(&resource).await);
         let x = emit_response!(c, resource => StatementType, last_modified)?;
-        Ok(EitherOr::JsonX(Box::new(GetResponse { inner: x })))
+        Ok(EitherOr::JsonX(Box::new(GetResponse {
+            inner: x,
+            consistent_thru,
+        })))
     }
 }
 
@@ -669,7 +1115,8 @@ async fn as_json<T: DeserializeOwned>(
         let mime = ct
             .parse::<Mime>()
             .unwrap_or_else(|x| panic!("Failed parsing CT: {x}"));
-        if mime != APPLICATION_JSON {
+        // compare essences only so parameters (e.g. charset) are tolerated...
+        if mime.essence_str() != APPLICATION_JSON.essence_str() {
             let msg = format!("Expected 'application/json' CT; got '{ct}'");
             error!("{}", msg);
             return Err(MyError::Runtime(msg.into()));
@@ -681,6 +1128,7 @@ async fn as_json<T: DeserializeOwned>(
     part.read_to_end(&mut buf)
         .await
         .unwrap_or_else(|x| panic!("Failed consuming Part: {x}"));
+    check_unique_keys(&buf)?;
     serde_json::from_slice::<T>(&buf).map_err(|x| {
         let msg = format!("Failed deserializing part: {x}");
         error!("{}", msg);
@@ -723,7 +1171,7 @@ async fn ingest_multipart(
                 .map_err(|x| x.with_status(Status::BadRequest))
                 .await?;
             for map in x.0 {
-                let y = Statement::from_json_obj(map)
+                let y = Statement::from_json_obj_bounded(map, config().max_json_depth)
                     .map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
                 statements.push(y)
             }
@@ -931,12 +1379,25 @@ async fn ingest_multipart(
 
 async fn persist_one(
     conn: &PgPool,
+    counters: &Counters,
     c: Headers,
+    uuid: Uuid,
     statement: &mut Statement,
     user: &User,
 ) -> Result<PutResponse, MyError> {
     debug!("statement = {}", statement);
 
+    if statement.id().is_none() {
+        statement.set_id(uuid)
+    } else if *statement.id().unwrap() != uuid {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: "Statement ID in URL does not match one in body".into(),
+        });
+    }
+
+    check_statement(&c, statement)?;
+
     let uuid = statement.id().unwrap();
     let x = statement_exists(conn, uuid).await?;
     match x {
@@ -994,7 +1455,7 @@ async fn persist_one(
             let (found, valid, id) = find_statement_to_void(conn, &target_uuid).await?;
             if found {
                 if valid {
-                    to_void_id = Some(id)
+                    to_void_id = Some((id, target_uuid))
                 } else {
                     return Err(MyError::HTTP {
                         status: Status::BadRequest,
@@ -1011,13 +1472,15 @@ async fn persist_one(
         }
     }
 
-    insert_statement(conn, statement).await?;
+    insert_statement(conn, statement, auditor(user)).await?;
+    counters.statements(1);
 
     // NOTE (rsn) 20240910 -if the Verb is 'voided' then void the target Statement...
-    if let Some(id) = to_void_id {
+    if let Some((id, target_uuid)) = to_void_id {
         debug!("About to void Statement #{}", id);
-        void_statement(conn, id).await?;
-        info!("Voided Statement #{}", id)
+        void_statements(conn, &[(id, target_uuid)], auditor(user)).await?;
+        info!("Voided Statement #{}", id);
+        counters.voided(1);
     }
 
     let etag = compute_etag::<Statement>(statement)?;
@@ -1049,12 +1512,17 @@ async fn persist_one(
 ///
 async fn persist_many(
     conn: &PgPool,
+    counters: &Counters,
     c: Headers,
     mut statements: Vec<Statement>,
     user: &User,
 ) -> Result<PostResponse, MyError> {
     debug!("statements = {:?}", statements);
 
+    for s in &mut statements {
+        check_statement(&c, s)?;
+    }
+
     // not every statement has a UUID; if it doesn't assign it one...
     // in the process, collect and verify that no 2 UUIDs are the same...
     let mut uuids = vec![];
@@ -1114,14 +1582,27 @@ async fn persist_many(
     // at this point all statements have an UUID and a timestamp.  before
     // persisting them though we must validate them wrt. to voiding...
     let mut ids_to_void = vec![];
+    let mut targets = HashSet::new();
     for s in &statements {
         if s.is_verb_voided() {
             if let Some(target_uuid) = s.voided_target() {
+                // same rules apply w/in the batch: a voider cannot target
+                // another voider, and a Statement can be voided only once...
+                let target_is_voider = statements
+                    .iter()
+                    .any(|x| x.id() == Some(&target_uuid) && x.is_verb_voided());
+                if target_is_voider || !targets.insert(target_uuid) {
+                    return Err(MyError::HTTP {
+                        status: Status::BadRequest,
+                        info: format!("Target of voiding statement ({target_uuid}) is invalid")
+                            .into(),
+                    });
+                }
                 // target Statement, if known, should not be a voiding one...
                 let (found, valid, id) = find_statement_to_void(conn, &target_uuid).await?;
                 if found {
                     if valid {
-                        ids_to_void.push(id)
+                        ids_to_void.push((id, target_uuid))
                     } else {
                         return Err(MyError::HTTP {
                             status: Status::BadRequest,
@@ -1142,10 +1623,7 @@ async fn persist_many(
 
     // otherwise, insert'em in the DB + collect their UUIDs...
     uuids.clear();
-    let n = statements.len();
-    for mut s in statements {
-        let uuid = *s.id().unwrap();
-
+    for s in &mut statements {
         // ensure `timestamp` is set... `stored` is set by the DB layer...
         // NOTE (rsn) 20241104 - however, in "4.2.4.2 Specific Statement Data
         // Requirements for an LRS", the spec also says "The LRS shall set the
@@ -1155,19 +1633,19 @@ async fn persist_many(
         //     s.set_timestamp_unchecked(Utc::now());
         // }
 
-        ensure_authority(&mut s, user)?;
-
-        debug!("Persisting Statement #{} (1 of {})...", uuid, n);
-        insert_statement(conn, &s).await?;
-        uuids.push(uuid);
+        ensure_authority(s, user)?;
     }
 
+    debug!("Persisting {} Statement(s)...", statements.len());
+    insert_statements(conn, &statements, auditor(user)).await?;
+    uuids.extend(statements.iter().map(|s| *s.id().unwrap()));
+    counters.statements(statements.len());
+
     // finally, void statements...
-    for id in ids_to_void {
-        debug!("About to void Statement #{}", id);
-        void_statement(conn, id).await?;
-        info!("Voided Statement #{}", id)
-    }
+    debug!("About to void {} Statement(s)", ids_to_void.len());
+    void_statements(conn, &ids_to_void, auditor(user)).await?;
+    info!("Voided {} Statement(s)", ids_to_void.len());
+    counters.voided(ids_to_void.len());
 
     // and return their UUIDs...
     let resource = StatementIDs(uuids);
@@ -1214,27 +1692,41 @@ async fn get_many(
 
     let (mut x, y) = find_statements_by_filter(conn, filter, format, sid).await?;
     if let Some(pi) = y {
-        let more = format!(
-            "statements/more/?sid={}&count={}&offset={}&limit={}&format={}&attachments={}",
-            sid,
-            pi.count,
-            pi.offset,
-            pi.limit,
-            format.as_param(),
-            with_attachments
-        );
-        let url = config().to_external_url(&more);
-        debug!("more URL = '{}'", url);
-        if let Err(z) = &x.set_more(&url) {
-            warn!(
-                "Failed updating `more` URL of StatementResult. Ignore + continue but StatementResult will be inaccurate: {}",
-                z
-            );
-        }
+        set_more_url(&mut x, sid, &pi, format, with_attachments)?;
     }
     Ok(x)
 }
 
+/// Set the `more` property of a paginated StatementResult to the external URL
+/// a client should use to fetch the next page.
+///
+/// Raise [MyError] if that URL is not a valid IRL --e.g. b/c of a malformed
+/// LRS_EXTERNAL_URL-- rather than returning an inaccurate result.
+fn set_more_url(
+    res: &mut StatementType,
+    sid: u64,
+    pi: &PagingInfo,
+    format: &Format,
+    attachments: bool,
+) -> Result<(), MyError> {
+    let more = format!(
+        "statements/more/?sid={}&count={}&offset={}&limit={}&format={}&attachments={}&ascending={}",
+        sid,
+        pi.count,
+        pi.offset,
+        pi.limit,
+        format.as_param(),
+        attachments,
+        pi.ascending
+    );
+    let url = config().to_external_url(&more);
+    debug!("more URL = '{}'", url);
+    res.set_more(&url).map_err(|x| {
+        error!("Failed setting `more` URL of StatementResult: {}", x);
+        MyError::Data(x).with_status(Status::InternalServerError)
+    })
+}
+
 /// Write the JSON serialized form of the given Statement array to a named local
 /// file inside 'static/s' folder path rooted at this project's home dir.
 /// Return the file's path if/when successful.
@@ -1292,6 +1784,13 @@ async fn save_attachment(bytes: Vec<u8>, part: &InPartInfo) -> Result<(), MyErro
     Ok(())
 }
 
+/// Return the timestamp to use as the `Consistent-Through` value of a response
+/// carrying `resource`; i.e. the most recent of the server's consistent-through
+/// timestamp and the `stored` one of the `resource`.
+async fn effective_consistent_through(resource: &StatementType) -> DateTime<Utc> {
+    get_consistent_thru().await.max(resource.stored())
+}
+
 fn consistent_through(timestamp: DateTime<Utc>) -> Header<'static> {
     Header::new(
         CONSISTENT_THRU_HDR,
@@ -1306,6 +1805,188 @@ fn last_modified(timestamp: DateTime<Utc>) -> Header<'static> {
     )
 }
 
+/// When the Request asks for _strict_ handling, apply the additional rules
+/// to the given Statement and raise a 400 if any of them is violated.
+fn check_strict(c: &Headers, s: &Statement) -> Result<(), MyError> {
+    if !c.is_strict() {
+        return Ok(());
+    }
+
+    reject_violations(s.validate_strict())
+}
+
+/// Raise a 400 listing the given `violations` if there are any.
+fn reject_violations(violations: Vec<ValidationError>) -> Result<(), MyError> {
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        let info = violations
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: info.into(),
+        })
+    }
+}
+
+/// Return the row ID of the user to record in the audit trail of the
+/// Statements they insert or void; `None` when running in Legacy mode.
+fn auditor(user: &User) -> Option<i32> {
+    if matches!(config().mode, Mode::Legacy) {
+        None
+    } else {
+        Some(user.id)
+    }
+}
+
+/// Read a request's `application/json` body up to the configured JSON_LIMIT.
+///
+/// NOTE (rsn) 20261016 - the limit is applied here rather than by merging it
+/// into Rocket's `string` one which is shared by every `&str` data guard.
+async fn read_json(data: Data<'_>) -> Result<Capped<String>, MyError> {
+    data.open(config().json_limit)
+        .into_string()
+        .await
+        .map_err(|x| MyError::IO(x).with_status(Status::BadRequest))
+}
+
+/// Reject w/ a 413 (Payload Too Large) a `json` body truncated at the
+/// configured JSON_LIMIT.
+fn complete_json(json: Capped<String>) -> Result<String, MyError> {
+    if json.is_complete() {
+        Ok(json.into_inner())
+    } else {
+        Err(MyError::HTTP {
+            status: Status::PayloadTooLarge,
+            info: format!("Request body exceeds {}", config().json_limit).into(),
+        })
+    }
+}
+
+/// Parse a PUT request's `json` body into a [Statement].
+fn statement_from_json(json: Capped<String>) -> Result<Statement, MyError> {
+    let json = complete_json(json)?;
+    check_unique_keys(json.as_bytes())?;
+    let map: Map<String, Value> = serde_json::from_str(&json)
+        .map_err(|x| MyError::Data(DataError::JSON(x)).with_status(Status::BadRequest))?;
+    let statement = Statement::from_json_obj_bounded(map, config().max_json_depth)
+        .map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
+
+    // NOTE (rsn) 202410004 /4.1.3 Content Types/ - When receiving a PUT or
+    // POST request with application/json content-type, an LRS shall respond
+    // w/ HTTP 400 Bad Request if, when present, Attachment objects in the
+    // Statement(s) do not have populated fileUrl property.
+    let count = statement
+        .attachments()
+        .iter()
+        .filter(|x| is_unresolved(x))
+        .count();
+    if count > 0 {
+        error!("Found {} Attachment(s) w/ unpopulated 'fileUrl'", count);
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("Found {count} Attachment(s) w/ unpopulated 'fileUrl'").into(),
+        });
+    }
+
+    Ok(statement)
+}
+
+/// Parse a POST request's `json` body into a collection of [Statement]s.
+fn statements_from_json(json: Capped<String>) -> Result<Vec<Statement>, MyError> {
+    let json = complete_json(json)?;
+    check_unique_keys(json.as_bytes())?;
+    let json: Statements = serde_json::from_str(&json)
+        .map_err(|x| MyError::Data(DataError::JSON(x)).with_status(Status::BadRequest))?;
+    let mut statements = vec![];
+    for map in json.0 {
+        let x = Statement::from_json_obj_bounded(map, config().max_json_depth)
+            .map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
+        statements.push(x)
+    }
+
+    // NOTE (rsn) 202410004 /4.1.3 Content Types/ - same as above.
+    let count = statements
+        .iter()
+        .flat_map(|x| x.attachments())
+        .filter(|x| is_unresolved(x))
+        .count();
+    if count > 0 {
+        return Err(MyError::HTTP {
+            status: Status::BadRequest,
+            info: format!("Statement w/ {count} unresolved Attachment(s)").into(),
+        });
+    }
+
+    Ok(statements)
+}
+
+/// When REJECT_DUPLICATE_KEYS is set, reject a `json` body w/ an Object that
+/// contains the same key more than once.
+fn check_unique_keys(json: &[u8]) -> Result<(), MyError> {
+    if config().reject_duplicate_keys {
+        check_duplicate_keys(json).map_err(|x| MyError::Data(x).with_status(Status::BadRequest))?;
+    }
+
+    Ok(())
+}
+
+/// Reject a [Statement] w/ Agent, Group or Activity names longer than the
+/// configured MAX_NAME_LENGTH.
+fn check_names(s: &Statement) -> Result<(), MyError> {
+    reject_violations(s.check_name_lengths(config().max_name_length))
+}
+
+/// When EXTENSIONS_ALLOWLIST is set, reject a [Statement] w/ Extensions keys
+/// not in that list.
+fn check_extensions(s: &Statement) -> Result<(), MyError> {
+    let allowed = &config().extensions_allowlist;
+    if allowed.is_empty() {
+        return Ok(());
+    }
+
+    reject_violations(s.check_extensions(allowed))
+}
+
+/// When HTTPS_FILE_URLS is set, reject a [Statement] w/ Attachments, incl.
+/// those of a SubStatement, whose `fileUrl` is not an HTTPS URL.
+fn check_file_urls(s: &Statement) -> Result<(), MyError> {
+    if !config().https_file_urls {
+        return Ok(());
+    }
+
+    reject_violations(s.check_file_urls())
+}
+
+/// Run the LRS-level checks every ingested [Statement] goes through.
+fn check_statement(c: &Headers, s: &mut Statement) -> Result<(), MyError> {
+    check_strict(c, s)?;
+    check_names(s)?;
+    check_extensions(s)?;
+    check_file_urls(s)?;
+    check_stored(s)
+}
+
+/// `stored` is assigned by the LRS. Reject a [Statement] that carries one
+/// when REJECT_CLIENT_STORED is set; otherwise discard it.
+fn check_stored(s: &mut Statement) -> Result<(), MyError> {
+    if s.stored().is_some() {
+        if config().reject_client_stored {
+            return Err(MyError::HTTP {
+                status: Status::BadRequest,
+                info: "Statement 'stored' is set by the LRS".into(),
+            });
+        }
+
+        s.unset_stored();
+    }
+
+    Ok(())
+}
+
 fn ensure_authority(s: &mut Statement, user: &User) -> Result<(), MyError> {
     if s.authority().is_none() {
         user.can_authorize_statement()?;
@@ -9,12 +9,13 @@ use crate::{
         schema::TVerb,
         verb::{
             ext_compute_aggregates, ext_find_by_iri, ext_find_by_rid, ext_find_some, ext_update,
-            insert_verb,
+            ext_usage, insert_verb,
         },
     },
     eval_preconditions,
     lrs::{DB, Headers, User, etag_from_str, no_content, resources::WithETag},
 };
+use chrono::{DateTime, Utc};
 use core::fmt;
 use iri_string::types::IriStr;
 use rocket::{
@@ -27,12 +28,16 @@ use rocket::{
     routes,
 };
 use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
+use sqlx::{FromRow, PgPool};
 use std::str::FromStr;
 use tracing::{debug, error, info, warn};
 
 const DEFAULT_START_RID: i32 = 0;
 const DEFAULT_COUNT: i32 = 50;
+// NOTE (rsn) 20261016 - unlike Statements, which default to the most recently
+// stored first (see `filter::DEFAULT_ASCENDING`), Verbs are listed from the
+// `start` row ID upwards by default. `start` is where the next page begins
+// when paging ascending; descending, it only bounds the lowest row ID.
 const DEFAULT_ASC: bool = true;
 
 #[derive(Debug, Serialize)]
@@ -49,6 +54,14 @@ pub struct VerbUI {
     pub(crate) display: String,
 }
 
+/// Number of Statements using a given Verb.
+#[derive(Debug, Deserialize, FromRow, Serialize)]
+pub struct VerbUsage {
+    pub(crate) rid: i32,
+    pub(crate) iri: String,
+    pub(crate) count: i64,
+}
+
 impl VerbUI {
     pub(crate) fn from(v: TVerb, language: &MyLanguageTag) -> Self {
         VerbUI {
@@ -190,6 +203,7 @@ pub fn routes() -> Vec<rocket::Route> {
         get_iri,
         get_rid,
         get_aggregates,
+        get_usage,
         get_some
     ]
 }
@@ -457,6 +471,31 @@ async fn get_aggregates(db: &State<DB>, user: User) -> Result<ETaggedResource, M
     tag_n_bag_it::<Aggregates>(x)
 }
 
+#[get("/usage?<since>")]
+async fn get_usage(
+    since: Option<&str>,
+    db: &State<DB>,
+    user: User,
+) -> Result<ETaggedResource, MyError> {
+    debug!("----- get_usage ----- {}", user);
+    user.can_use_verbs()?;
+
+    let since = match since {
+        Some(x) => Some(
+            DateTime::parse_from_rfc3339(x)
+                .map_err(|x| MyError::HTTP {
+                    status: Status::BadRequest,
+                    info: format!("Failed parsing 'since': {x}").into(),
+                })?
+                .with_timezone(&Utc),
+        ),
+        None => None,
+    };
+    debug!("since = {:?}", since);
+    let x = ext_usage(db.pool(), since).await?;
+    tag_n_bag_it::<Vec<VerbUsage>>(x)
+}
+
 #[get("/")]
 async fn get_some(
     q: QueryParams<'_>,
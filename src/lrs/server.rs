@@ -11,7 +11,7 @@ use rocket::{
     form::FromForm,
     fs::{FileServer, relative},
     futures::lock::Mutex,
-    http::{Header, Method},
+    http::{Header, Method, Status},
     response::status,
     time::{OffsetDateTime, format_description::well_known::Rfc2822},
 };
@@ -72,7 +72,12 @@ async fn update_consistent_thru() {
 /// or not. When `testing` is TRUE a mock DB is injected otherwise it's the
 /// real McKoy.
 pub fn build(testing: bool) -> Rocket<Build> {
-    let figment = rocket::Config::figment();
+    // NOTE (rsn) 20261016 - the multipart request body limit comes from our
+    // configuration, taking precedence over the Rocket.toml one. the JSON one
+    // is applied by the Statement resource handlers themselves so the `string`
+    // limit of other routes (State and Profile documents) is left alone...
+    let figment =
+        rocket::Config::figment().merge(("limits.file/multipart", config().multipart_limit));
     fs::create_dir_all(relative!("static")).expect("Failed creating 'static' dir :(");
     rocket::custom(figment)
         .mount("/about", resources::about::routes())
@@ -82,13 +87,17 @@ pub fn build(testing: bool) -> Rocket<Build> {
         .mount("/agents", resources::agents::routes())
         .mount("/agents/profile", resources::agent_profile::routes())
         .mount("/statements", resources::statement::routes())
+        .mount("/metrics", resources::metrics::routes())
         // extensions...
         .mount(prepend_slash(VERBS_EXT_BASE), resources::verbs::routes())
         .mount(prepend_slash(STATS_EXT_BASE), resources::stats::routes())
         .mount(prepend_slash(USERS_EXT_BASE), resources::users::routes())
+        // administration...
+        .mount("/admin", resources::admin::routes())
         // assets...
         .mount("/static", FileServer::from(relative!("static")))
         .attach(DB::fairing(testing))
+        .manage(resources::metrics::Counters::default())
         // startup hook
         .attach(AdHoc::on_liftoff("Liftoff Hook", move |_| {
             Box::pin(async move {
@@ -110,6 +119,11 @@ pub fn build(testing: bool) -> Rocket<Build> {
                         if let Err(x) = tmp {
                             warn!("Failed: {}", x);
                         }
+                        if config().resumable_uploads
+                            && let Err(x) = resources::statement::clean_partial_uploads()
+                        {
+                            warn!("Failed: {}", x);
+                        }
                     }
                 });
             })
@@ -168,7 +182,13 @@ pub fn build(testing: bool) -> Rocket<Build> {
         // wire the catchers...
         .register(
             "/",
-            catchers![bad_request, unauthorized, not_found, unknown_route],
+            catchers![
+                bad_request,
+                unauthorized,
+                not_found,
+                payload_too_large,
+                unknown_route
+            ],
         )
 }
 
@@ -194,6 +214,26 @@ pub(crate) fn qp<'r, T: FromForm<'r>>(req: &'r Request<'_>, name: &str) -> Optio
     }
 }
 
+/// Same as [qp] except that a parameter present w/ a value that can't be
+/// processed, is not treated as absent but raises a 400 [MyError] instead.
+pub(crate) fn strict_qp<'r, T: FromForm<'r>>(
+    req: &'r Request<'_>,
+    name: &str,
+) -> Result<Option<T>, MyError> {
+    match req.query_value::<T>(name) {
+        Some(Ok(x)) => Ok(Some(x)),
+        Some(Err(x)) => {
+            let msg = format!("Failed processing query parameter '{name}': {x}");
+            error!("{}", msg);
+            Err(MyError::HTTP {
+                status: Status::BadRequest,
+                info: msg.into(),
+            })
+        }
+        None => Ok(None),
+    }
+}
+
 #[catch(400)]
 fn bad_request(req: &Request) -> &'static str {
     error!("----- 400 -----");
@@ -214,6 +254,18 @@ fn not_found(req: &Request) -> &'static str {
     "404 - Resource not found :("
 }
 
+#[catch(413)]
+fn payload_too_large(req: &Request) -> String {
+    error!("----- 413 -----");
+    debug!("req = {:?}", req);
+    let limit = if req.content_type().is_some_and(|x| x.top() == "multipart") {
+        config().multipart_limit
+    } else {
+        config().json_limit
+    };
+    format!("413 - Payload too large: request body exceeds {limit}")
+}
+
 #[catch(422)]
 fn unknown_route(req: &Request) -> status::BadRequest<String> {
     error!("----- 422 -----");
@@ -1,6 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::lrs::resources::stats::update_stats;
+use crate::{
+    config,
+    lrs::{
+        RESPONSE_TIME_HDR,
+        resources::{metrics::Counters, stats::update_stats},
+    },
+};
 use chrono::{DateTime, SecondsFormat, Utc};
 use rocket::{
     Data, Request, Response,
@@ -29,8 +35,12 @@ impl Fairing for StopWatch {
     }
 
     /// Add a response header showing arrival time and duration we took to
-    /// process said request.
+    /// process said request. When configured, also add one w/ just the
+    /// duration in milliseconds.
     async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let Some(counters) = req.rocket().state::<Counters>() {
+            counters.status(res.status());
+        }
         let timer = req.local_cache(|| TimerStart(None));
         let value = if let Some(arrival_time) = timer.0.as_ref() {
             let duration = Utc::now()
@@ -48,7 +58,11 @@ impl Fairing for StopWatch {
                     } else {
                         error!("Failed finding route of {}", req);
                     }
-                    format!("{:.3}", ns as f64 / 1_000_000.0)
+                    let ms = format!("{:.3}", ns as f64 / 1_000_000.0);
+                    if config().response_time_header {
+                        res.set_raw_header(RESPONSE_TIME_HDR, ms.clone());
+                    }
+                    ms
                 }
                 None => {
                     error!("Failed computing request duration");
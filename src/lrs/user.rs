@@ -234,6 +234,18 @@ impl User {
         }
     }
 
+    pub(crate) fn can_view_metrics(&self) -> Result<(), MyError> {
+        self.check_is_enabled()?;
+        if !matches!(self.role, Role::Root | Role::Admin) {
+            Err(MyError::HTTP {
+                status: Status::Forbidden,
+                info: format!("User {self} is NOT authorized to view metrics").into(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     pub(crate) fn is_root(&self) -> bool {
         matches!(self.role, Role::Root)
     }
@@ -276,6 +288,9 @@ impl<'r> FromRequest<'r> for User {
     type Error = MyError;
 
     async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        // NOTE (rsn) 20261016 - missing or unknown credentials forward w/ a
+        // 401 status. unless another route handles the request, the 401
+        // catcher then responds w/ the `WWW-Authenticate` Basic challenge.
         // which mode are we running?
         match config().mode {
             crate::Mode::Legacy => Outcome::Success(User::default()),
@@ -285,7 +300,10 @@ impl<'r> FromRequest<'r> for User {
                 match req.headers().get_one(header::AUTHORIZATION.as_str()) {
                     Some(basic_auth) => {
                         let trimmed = basic_auth.trim();
-                        if trimmed[..6].to_lowercase() != *"basic " {
+                        if trimmed
+                            .get(..6)
+                            .is_none_or(|x| !x.eq_ignore_ascii_case("basic "))
+                        {
                             let msg = "Invalid Authorization header";
                             error!("Failed: {}", msg);
                             Outcome::Error((Status::BadRequest, MyError::Runtime(msg.into())))
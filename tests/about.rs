@@ -8,7 +8,9 @@ use serde_json::Value;
 use test_context::test_context;
 use tracing_test::traced_test;
 use utils::{accept_json, authorization, MyTestContext};
-use xapi_rs::{config, About, Extensions, MyError, MyVersion, EXT_STATS, EXT_USERS, EXT_VERBS};
+use xapi_rs::{
+    About, Extensions, MyError, MyVersion, EXT_STATS, EXT_USERS, EXT_VERBS, RESPONSE_TIME_HDR,
+};
 
 const ABOUT_ETAG: &str = "\"270-280210938554353665209709493369712356295\"";
 
@@ -45,6 +47,26 @@ fn test_get(ctx: &mut MyTestContext) -> Result<(), MyError> {
     Ok(())
 }
 
+/// W/ RESPONSE_TIME_HEADER off (the default), there's no `X-Response-Time-Ms`
+/// header. See `flags.rs` for when it's on.
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_response_time(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    let req = client
+        .get("/about")
+        .header(accept_json())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    assert!(resp.headers().get_one(RESPONSE_TIME_HDR).is_none());
+
+    Ok(())
+}
+
 #[test_context(MyTestContext)]
 #[traced_test]
 #[test]
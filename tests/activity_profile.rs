@@ -91,6 +91,7 @@ fn test_endpoint(ctx: &mut MyTestContext) -> Result<(), MyError> {
             resources::activity_profile::get(
                 activityId = r#"http://www.example.com/meetings/occurances/34534"#,
                 profileId = _,
+                profileIds = _,
                 since = Some(now.to_rfc3339()),
             )
         ))
@@ -152,6 +153,7 @@ fn test_endpoint(ctx: &mut MyTestContext) -> Result<(), MyError> {
             resources::activity_profile::get(
                 activityId = r#"http://www.example.com/meetings/occurances/34534"#,
                 profileId = _,
+                profileIds = _,
                 since = Some(now.to_rfc3339()),
             )
         ))
@@ -246,6 +248,7 @@ fn test_put_non_json_ok(ctx: &mut MyTestContext) -> Result<(), MyError> {
             resources::activity_profile::get(
                 activityId = r#"http://www.example.com/meeting/100"#,
                 profileId = _,
+                profileIds = _,
                 since = _,
             )
         ))
@@ -314,3 +317,78 @@ fn test_delete_404_is_204(ctx: &mut MyTestContext) -> Result<(), MyError> {
 
     Ok(())
 }
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_get_many(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const ACTIVITY_ID: &str = "http://www.example.com/meeting/42";
+
+    let client = &ctx.client;
+
+    // 1. store 3 profiles...
+    for (id, doc) in [
+        ("0001", r#"{"x":1}"#),
+        ("0002", r#"{"x":2}"#),
+        ("0003", r#"{"x":3}"#),
+    ] {
+        let req = client
+            .put(uri!(
+                "/activities/profile",
+                resources::activity_profile::put(activityId = ACTIVITY_ID, profileId = id)
+            ))
+            .body(doc)
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::NoContent);
+    }
+
+    // 2. fetch 2 of them in one go...
+    let req = client
+        .get(uri!(
+            "/activities/profile",
+            resources::activity_profile::get(
+                activityId = ACTIVITY_ID,
+                profileId = _,
+                profileIds = Some("0001,0003"),
+                since = _,
+            )
+        ))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    assert_eq!(resp.content_type(), Some(ContentType::JSON));
+    let json = resp.into_string().unwrap();
+    let docs: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&json).unwrap();
+    assert_eq!(docs.len(), 2);
+    assert_eq!(docs["0001"]["x"], 1);
+    assert_eq!(docs["0003"]["x"], 3);
+    assert!(!docs.contains_key("0002"));
+
+    // 3. combining `profileIds` w/ `profileId` is an error...
+    let req = client
+        .get(uri!(
+            "/activities/profile",
+            resources::activity_profile::get(
+                activityId = ACTIVITY_ID,
+                profileId = Some("0002"),
+                profileIds = Some("0001,0003"),
+                since = _,
+            )
+        ))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
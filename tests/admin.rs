@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use rocket::http::{ContentType, Status};
+use serde_json::Value;
+use test_context::test_context;
+use tracing_test::traced_test;
+use utils::{accept_json, authorization, v2, MyTestContext};
+use xapi_rs::MyError;
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_audit(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    skip_if_legacy!();
+
+    const S1: &str = r#"{
+"id":"01930f4e-1c2d-7e3f-8a4b-5c6d7e8f9a0b",
+"actor":{"objectType":"Agent","name":"agent 86","mbox":"mailto:a86@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{"id":"http://www.example.com/ceremony/ref/101"}}"#;
+    const S2: &str = r#"{
+"actor":{"objectType":"Agent","name":"agent 86","mbox":"mailto:a86@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided","display":{"en-US":"voided"}},
+"object":{"objectType":"StatementRef","id":"01930f4e-1c2d-7e3f-8a4b-5c6d7e8f9a0b"}}"#;
+
+    let client = &ctx.client;
+
+    // 1. insert a Statement, then void it...
+    for s in [S1, S2] {
+        let req = client
+            .post("/statements")
+            .body(s)
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+    }
+
+    // 2. its audit trail should contain 2 entries...
+    let req = client
+        .get("/admin/audit?statementId=01930f4e-1c2d-7e3f-8a4b-5c6d7e8f9a0b")
+        .header(accept_json())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let json = resp.into_json::<Vec<Value>>().expect("Failed deserializing audit trail");
+    assert_eq!(json.len(), 2);
+    assert_eq!(json[0]["action"], "insert");
+    assert_eq!(json[1]["action"], "void");
+    assert_eq!(json[0]["userId"], json[1]["userId"]);
+
+    Ok(())
+}
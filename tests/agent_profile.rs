@@ -91,6 +91,7 @@ fn test_endpoint(ctx: &mut MyTestContext) -> Result<(), MyError> {
             resources::agent_profile::get(
                 agent = r#"{"objectType":"Agent","mbox":"foo@nowhere.net"}"#,
                 profileId = _,
+                profileIds = _,
                 since = Some(now.to_rfc3339()),
             )
         ))
@@ -151,6 +152,7 @@ fn test_endpoint(ctx: &mut MyTestContext) -> Result<(), MyError> {
             resources::agent_profile::get(
                 agent = r#"{"objectType":"Agent","mbox":"foo@nowhere.net"}"#,
                 profileId = _,
+                profileIds = _,
                 since = Some(now.to_rfc3339()),
             )
         ))
@@ -179,7 +181,12 @@ fn test_get_invalid_agent_err(ctx: &mut MyTestContext) -> Result<(), MyError> {
     let req = client
         .get(uri!(
             "/agents/profile",
-            resources::agent_profile::get(agent = r#"foo"#, profileId = Some("0001"), since = _,)
+            resources::agent_profile::get(
+                agent = r#"foo"#,
+                profileId = Some("0001"),
+                profileIds = _,
+                since = _,
+            )
         ))
         .header(ContentType::JSON)
         .header(accept_json())
@@ -34,6 +34,24 @@ fn test_w_body(ctx: &mut MyTestContext) -> Result<(), MyError> {
     Ok(())
 }
 
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_wo_agent(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    let req = client
+        .get("/agents")
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
 #[test_context(MyTestContext)]
 #[traced_test]
 #[test]
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// NOTE (rsn) 20261016 - kept in its own test binary so no other test updates
+// the server's consistent-through timestamp while this one runs.
+
+mod utils;
+
+use chrono::{DateTime, FixedOffset};
+use rocket::http::{ContentType, Status};
+use test_context::test_context;
+use tracing_test::traced_test;
+use utils::{accept_json, authorization, v2, MyTestContext};
+use xapi_rs::{MyError, StatementIDs, CONSISTENT_THRU_HDR};
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_json_and_multipart_agree(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let uuid = resp
+        .into_json::<StatementIDs>()
+        .expect("Failed deserializing array of UUIDs")
+        .0[0];
+
+    // 1. as JSON...
+    let req = client
+        .get(format!("/statements/?statementId={uuid}"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let json_xct = resp
+        .headers()
+        .get_one(CONSISTENT_THRU_HDR)
+        .map(parse)
+        .expect("Missing JSON Consistent-Through");
+
+    // 2. as multipart/mixed where the header is in the 1st part...
+    let req = client
+        .get(format!("/statements/?statementId={uuid}&attachments=true"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let body = resp.into_string().unwrap();
+    let prefix = format!("{}: ", CONSISTENT_THRU_HDR.to_lowercase());
+    let start = body.to_lowercase().find(&prefix).expect("Missing part XCT") + prefix.len();
+    let end = start + body[start..].find("\r\n").unwrap();
+    let mixed_xct = parse(&body[start..end]);
+
+    assert_eq!(json_xct, mixed_xct);
+
+    Ok(())
+}
+
+fn parse(s: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(s.trim()).expect("Invalid Consistent-Through")
+}
@@ -169,6 +169,35 @@ fn test_substmt_w_version_should_fail(ctx: &mut MyTestContext) -> Result<(), MyE
     Ok(())
 }
 
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_substmt_w_id_err(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{
+  "objectType":"SubStatement",
+  "id":"01932d1e-a584-79d2-b83a-6b380546b21c",
+  "actor":{"objectType":"Agent","mbox_sha1sum":"cd9b00a5611f94eaa7b1661edab976068e364975"},
+  "verb":{"id":"http://adlnet.gov/expapi/verbs/reported","display":{"en-GB":"reported"}},
+  "object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2());
+    let resp = req.dispatch();
+
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
 #[test_context(MyTestContext)]
 #[traced_test]
 #[test]
@@ -526,6 +555,75 @@ fn test_voiding(ctx: &mut MyTestContext) -> Result<(), MyError> {
     Ok(())
 }
 
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_voiding_chain(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S1: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI mbox","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"id":"3b1c6a0e-8f4d-4c52-9a7e-1d2f3e4a5b6c"}"#;
+    // voids S1...
+    const S2: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI mbox","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided","display":{"en-US":"voided"}},
+"object":{"objectType":"StatementRef","id":"3b1c6a0e-8f4d-4c52-9a7e-1d2f3e4a5b6c"},
+"id":"4c2d7b1f-9a5e-4d63-8b8f-2e3a4f5b6c7d"}"#;
+    // voids S2 which is itself a voiding statement...
+    const S3: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI mbox","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided","display":{"en-US":"voided"}},
+"object":{"objectType":"StatementRef","id":"4c2d7b1f-9a5e-4d63-8b8f-2e3a4f5b6c7d"},
+"id":"5d3e8c2a-0b6f-4e74-9c9a-3f4b5a6c7d8e"}"#;
+    // voids S1 again, after it was already voided by S2...
+    const S4: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI mbox","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided","display":{"en-US":"voided"}},
+"object":{"objectType":"StatementRef","id":"3b1c6a0e-8f4d-4c52-9a7e-1d2f3e4a5b6c"},
+"id":"6e4f9d3b-1c7a-4f85-8dab-4a5c6b7d8e9f"}"#;
+
+    let client = &ctx.client;
+
+    for (s, expected) in [
+        (S1, Status::Ok),
+        (S2, Status::Ok),
+        (S3, Status::BadRequest),
+        (S4, Status::BadRequest),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(s)
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), expected);
+    }
+
+    // same chain submitted as a batch should be rejected in its entirety...
+    let batch = format!(
+        "[{}, {}, {}]",
+        S1.replace("3b1c6a0e", "7f5a0e4c"),
+        S2.replace("3b1c6a0e", "7f5a0e4c").replace("4c2d7b1f", "8a6b1f5d"),
+        S3.replace("4c2d7b1f", "8a6b1f5d").replace("5d3e8c2a", "9b7c2a6e")
+    );
+    let req = client
+        .post("/statements")
+        .body(batch)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
 #[test_context(MyTestContext)]
 #[traced_test]
 #[test]
@@ -771,8 +771,10 @@ fn test_strict_signed_statement(ctx: &mut MyTestContext) -> Result<(), MyError>
     hasher = Sha256::new();
     hasher.update(compact_sig.as_bytes());
     let digest = hex::encode(hasher.finish());
+    let en = MyLanguageTag::from_str("en-US")?;
     let signature_att = Attachment::builder()
         .usage_type(SIGNATURE_UT)?
+        .display(&en, "Signature")?
         .content_type(SIGNATURE_CT)?
         .sha2(&digest)?
         .length(
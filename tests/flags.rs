@@ -0,0 +1,335 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests exercising configuration flags that are off by default.
+
+mod utils;
+
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use openssl::sha::sha256;
+use rocket::http::{ContentType, Header, Status, hyper::header};
+use std::{fs, path::Path, time::Duration};
+use test_context::{TestContext, test_context};
+use tracing_test::traced_test;
+use utils::{MyTestContext, accept_json, authorization, set_env_once, v2};
+use uuid::Uuid;
+use xapi_rs::{MyError, RESPONSE_TIME_HDR};
+
+/// Non-default configuration values in effect for every test in this file.
+const FLAGS: &[(&str, &str)] = &[
+    ("HTTPS_FILE_URLS", "true"),
+    ("RESUMABLE_UPLOADS", "true"),
+    ("EXTENSIONS_ALLOWLIST", "http://example.com/ext/allowed"),
+    ("REJECT_CLIENT_STORED", "true"),
+    ("RESPONSE_TIME_HEADER", "true"),
+    ("REJECT_DUPLICATE_KEYS", "true"),
+];
+
+/// Same as [MyTestContext] but w/ the [FLAGS] set.
+struct FlagsContext(MyTestContext);
+
+impl TestContext for FlagsContext {
+    fn setup() -> FlagsContext {
+        set_env_once(FLAGS);
+        FlagsContext(MyTestContext::setup())
+    }
+
+    fn teardown(self) {
+        self.0.teardown();
+    }
+}
+
+#[test_context(FlagsContext)]
+#[traced_test]
+#[test]
+fn test_https_file_urls(ctx: &mut FlagsContext) -> Result<(), MyError> {
+    const A: &str = r#"{
+"usageType":"http://example.com/attachment-usage/test",
+"display":{"en-US":"A test attachment"},
+"contentType":"text/plain",
+"length":27,
+"sha2":"495395e777cd98da653df9615d09c0fd6bb2f8d4788394cd53c56a3bfdcd848a",
+"fileUrl":"URL"}"#;
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{
+  "objectType":"SubStatement",
+  "actor":{"objectType":"Agent","mbox":"mailto:sample.agent@example.com"},
+  "verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+  "object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+  "attachments":[SUB]},
+"attachments":[TOP]}"#;
+
+    let client = &ctx.0.client;
+
+    let https = A.replace("URL", "https://example.com/a.txt");
+    let http = A.replace("URL", "http://example.com/a.txt");
+    for (top, sub, status) in [
+        (&https, &https, Status::Ok),
+        (&http, &https, Status::BadRequest),
+        // a SubStatement's Attachments are checked too...
+        (&https, &http, Status::BadRequest),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(S.replace("TOP", top).replace("SUB", sub))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+#[test_context(FlagsContext)]
+#[traced_test]
+#[test]
+fn test_resumable_upload(ctx: &mut FlagsContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"attachments":[{
+    "usageType":"http://example.com/attachment-usage/test",
+    "display":{"en-US":"A resumable attachment"},
+    "contentType":"text/plain",
+    "length":LEN,
+    "sha2":"SHA2"
+}]}"#;
+
+    let client = &ctx.0.client;
+
+    // use unique contents to ensure they're not already known to the LRS...
+    let uuid = Uuid::now_v7();
+    let contents = format!("here is a resumable attachment #{uuid}");
+    let len = contents.len();
+    let sha2 = hex::encode(sha256(contents.as_bytes()));
+    let url = format!("/statements/attachments/{sha2}");
+
+    // 1. upload the contents in 2 ranged chunks...
+    let mid = len / 2;
+    let req = client
+        .put(&url)
+        .body(&contents[..mid])
+        .header(Header::new(
+            header::CONTENT_RANGE.as_str(),
+            format!("bytes 0-{}/{len}", mid - 1),
+        ))
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Accepted);
+    let range = resp.headers().get_one(header::RANGE.as_str());
+    assert_eq!(range, Some(format!("bytes=0-{}", mid - 1).as_str()));
+
+    // 2. sending the wrong chunk next, or one for contents larger than the
+    //    configured limit, should fail...
+    let req = client
+        .put(&url)
+        .body(&contents[..mid])
+        .header(Header::new(
+            header::CONTENT_RANGE.as_str(),
+            format!("bytes 0-{}/{len}", mid - 1),
+        ))
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Conflict);
+
+    let req = client
+        .put(&url)
+        .body(&contents[mid..])
+        .header(Header::new(
+            header::CONTENT_RANGE.as_str(),
+            format!("bytes {mid}-{}/{}", len - 1, u64::MAX),
+        ))
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::PayloadTooLarge);
+
+    let req = client
+        .put(&url)
+        .body(&contents[mid..])
+        .header(Header::new(
+            header::CONTENT_RANGE.as_str(),
+            format!("bytes {mid}-{}/{len}", len - 1),
+        ))
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::NoContent);
+
+    // 3. POST a Statement referencing it w/ no fileUrl. it's only resolved
+    //    when the declared length matches that of the uploaded contents...
+    let s = S
+        .replace("ID", &Uuid::now_v7().to_string())
+        .replace("LEN", &(len + 1).to_string())
+        .replace("SHA2", &sha2);
+    let req = client
+        .post("/statements")
+        .body(s)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    let s = S
+        .replace("ID", &uuid.to_string())
+        .replace("LEN", &len.to_string())
+        .replace("SHA2", &sha2);
+    let req = client
+        .post("/statements")
+        .body(s)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    // 4. the uploaded contents should be sent along the Statement...
+    let req = client
+        .get(format!("/statements/?statementId={uuid}&attachments=true"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let multipart = resp.into_string().unwrap();
+    assert!(multipart.contains(&contents));
+
+    // 5. don't leave the uploaded contents behind...
+    let name = BASE64_URL_SAFE_NO_PAD.encode(sha256(&hex::decode(&sha2).unwrap()));
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("static")
+        .join(format!("_{name}"));
+    fs::remove_file(path).expect("Failed removing uploaded Attachment");
+
+    Ok(())
+}
+
+#[test_context(FlagsContext)]
+#[traced_test]
+#[test]
+fn test_extensions_allowlist(ctx: &mut FlagsContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"context":{"extensions":{"KEY":"a value"}}}"#;
+
+    let client = &ctx.0.client;
+
+    for (key, status) in [
+        ("http://example.com/ext/allowed", Status::Ok),
+        ("http://example.com/ext/not-allowed", Status::BadRequest),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(S.replace("KEY", key))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+/// W/ REJECT_CLIENT_STORED on, a Statement w/ a `stored` property is rejected
+/// and not persisted.
+#[test_context(FlagsContext)]
+#[traced_test]
+#[test]
+fn test_client_stored(ctx: &mut FlagsContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"stored":"2013-05-18T05:32:34.804Z"}"#;
+
+    let client = &ctx.0.client;
+
+    let uuid = Uuid::now_v7();
+    let req = client
+        .post("/statements")
+        .body(S.replace("ID", &uuid.to_string()))
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    let req = client
+        .get(format!("/statements/?statementId={uuid}"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::NotFound);
+
+    Ok(())
+}
+
+/// W/ RESPONSE_TIME_HEADER on, every response carries an `X-Response-Time-Ms`
+/// header whose value is the time, in milliseconds, taken to handle it.
+#[test_context(FlagsContext)]
+#[traced_test]
+#[test]
+fn test_response_time(ctx: &mut FlagsContext) -> Result<(), MyError> {
+    let client = &ctx.0.client;
+
+    let req = client
+        .get("/about")
+        .header(accept_json())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let ms = resp
+        .headers()
+        .get_one(RESPONSE_TIME_HDR)
+        .expect("Missing response time header")
+        .parse::<f64>()
+        .expect("Failed parsing response time");
+    let duration = Duration::try_from_secs_f64(ms / 1000.0).expect("Invalid response time");
+    assert!(duration < Duration::from_secs(60));
+
+    Ok(())
+}
+
+/// W/ REJECT_DUPLICATE_KEYS on, a Statement w/ a duplicated key is rejected.
+#[test_context(FlagsContext)]
+#[traced_test]
+#[test]
+fn test_duplicate_keys(ctx: &mut FlagsContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+    let client = &ctx.0.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
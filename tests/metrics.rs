@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+use std::collections::HashMap;
+use test_context::{TestContext, test_context};
+use tracing_test::traced_test;
+use utils::{MyTestContext, accept_json, authorization, set_env_once, v2};
+use uuid::Uuid;
+use xapi_rs::MyError;
+
+/// Same as [MyTestContext] but w/ the `/metrics` end-point, disabled by
+/// default, enabled.
+struct MetricsContext(MyTestContext);
+
+impl TestContext for MetricsContext {
+    fn setup() -> MetricsContext {
+        set_env_once(&[("METRICS_ENABLED", "true")]);
+        MetricsContext(MyTestContext::setup())
+    }
+
+    fn teardown(self) {
+        self.0.teardown();
+    }
+}
+
+/// Scrape `/metrics` and return the samples it contains keyed by their
+/// name + labels.
+fn scrape(client: &Client) -> HashMap<String, u64> {
+    let req = client.get("/metrics").header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let ct = resp.content_type().expect("Missing Content-Type");
+    assert!(ct.is_plain());
+
+    resp.into_string()
+        .unwrap()
+        .lines()
+        .filter(|x| !x.starts_with('#'))
+        .map(|x| {
+            let (k, v) = x.rsplit_once(' ').expect("Malformed sample");
+            (k.to_owned(), v.parse().expect("Malformed counter"))
+        })
+        .collect()
+}
+
+#[test_context(MetricsContext)]
+#[traced_test]
+#[test]
+fn test_scrape(ctx: &mut MetricsContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+    const V: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided","display":{"en-US":"voided"}},
+"object":{"objectType":"StatementRef","id":"ID"}}"#;
+
+    let client = &ctx.0.client;
+
+    let before = scrape(client);
+
+    // 1. POST a Statement + void it...
+    let uuid = Uuid::now_v7().to_string();
+    for s in [S, V] {
+        let req = client
+            .post("/statements")
+            .body(s.replace("ID", &uuid))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+    }
+
+    // 2. counters, fresh for every test's server instance, should reflect
+    //    exactly that activity; incl. the 1st scrape...
+    let after = scrape(client);
+    assert_eq!(after["statements_total"], before["statements_total"] + 2);
+    assert_eq!(
+        after["statements_voided_total"],
+        before["statements_voided_total"] + 1
+    );
+    let ok = r#"requests_by_status_total{status="200"}"#;
+    assert_eq!(after[ok], before.get(ok).copied().unwrap_or(0) + 3);
+
+    Ok(())
+}
+
+#[test_context(MetricsContext)]
+#[traced_test]
+#[test]
+fn test_rejections(ctx: &mut MetricsContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/VERB"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+    // missing 'actor'...
+    const BAD: &str = r#"{
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+    let client = &ctx.0.client;
+
+    let before = scrape(client);
+
+    // 1. a good one, an invalid one, and one w/ same UUID as the 1st but
+    //    different content...
+    let uuid = Uuid::now_v7().to_string();
+    let good = S.replace("ID", &uuid).replace("VERB", "attended");
+    let conflicting = S.replace("ID", &uuid).replace("VERB", "attempted");
+    for (s, status) in [
+        (good.as_str(), Status::Ok),
+        (BAD, Status::BadRequest),
+        (conflicting.as_str(), Status::Conflict),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(s)
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    // 2. a PUT w/ a malformed statementId...
+    let req = client
+        .put("/statements/?statementId=not-a-uuid")
+        .body(&good)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    // 3. counters should reflect exactly those outcomes...
+    let after = scrape(client);
+    let count = |m: &HashMap<String, u64>, k: &str| m.get(k).copied().unwrap_or(0);
+    assert_eq!(after["statements_total"], before["statements_total"] + 1);
+    for (reason, delta) in [("validation", 2), ("conflict", 1)] {
+        let k = format!(r#"statements_rejected_total{{reason="{reason}"}}"#);
+        assert_eq!(count(&after, &k), count(&before, &k) + delta);
+    }
+
+    Ok(())
+}
@@ -15,7 +15,7 @@ use test_context::test_context;
 use tracing::debug;
 use tracing_test::traced_test;
 use utils::{accept_json, authorization, if_match, if_none_match, v2, MyTestContext};
-use xapi_rs::{resources, MyError};
+use xapi_rs::{config, resources, MyError};
 
 #[test_context(MyTestContext)]
 #[traced_test]
@@ -357,6 +357,105 @@ fn test_post_get_etags(ctx: &mut MyTestContext) -> Result<(), MyError> {
     Ok(())
 }
 
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_cache_control(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const DOC: &str = r#"{"car":"Elan"}"#;
+    const AGENT: &str = r#"{"objectType":"Agent","account":{"homePage":"http://www.example.com/agent/99","name":"Agent 99"}}"#;
+
+    let client = &ctx.client;
+
+    // 1. POST a State...
+    let req = client
+        .post(uri!(
+            "/activities/state",
+            resources::state::post(
+                activityId = "http://www.example.com/activity",
+                agent = AGENT,
+                registration = _,
+                stateId = "cached"
+            )
+        ))
+        .body(DOC)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::NoContent);
+
+    // 2. GET it + check the configured Cache-Control is there...
+    let req = client
+        .get(uri!(
+            "/activities/state",
+            resources::state::get(
+                activityId = "http://www.example.com/activity",
+                agent = AGENT,
+                registration = _,
+                stateId = Some("cached"),
+                since = _
+            )
+        ))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let cc_hdr = resp.headers().get_one(header::CACHE_CONTROL.as_str());
+    assert_eq!(cc_hdr, Some(config().doc_cache_control.as_str()));
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_doc_limit(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const AGENT: &str = r#"{"objectType":"Agent","mbox":"mailto:limit@example.com"}"#;
+
+    let client = &ctx.client;
+
+    // State documents are bound by Rocket's `string` limit, not JSON_LIMIT.
+    // a body exceeding it fails the `&str` data guard w/ a 400...
+    let limit = client
+        .rocket()
+        .config()
+        .limits
+        .get("string")
+        .expect("Missing 'string' limit");
+    assert!(limit < config().json_limit);
+
+    let doc = |size: usize| format!(r#"{{"k":"{}"}}"#, "x".repeat(size - 8));
+    for (size, status) in [
+        (limit.as_u64() as usize, Status::NoContent),
+        (limit.as_u64() as usize + 1, Status::BadRequest),
+    ] {
+        let req = client
+            .put(uri!(
+                "/activities/state",
+                resources::state::put(
+                    activityId = "http://www.example.com/activity",
+                    agent = AGENT,
+                    registration = _,
+                    stateId = "limit"
+                )
+            ))
+            .body(doc(size))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
 fn check_last_modified(resp: &LocalResponse, marker: DateTime<Utc>) {
     let last_modified_hdr = resp.headers().get_one(header::LAST_MODIFIED.as_str());
     assert!(last_modified_hdr.is_some());
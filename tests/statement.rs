@@ -10,7 +10,8 @@ use rocket::{
     serde::json::from_str,
     uri,
 };
-use std::str::FromStr;
+use serde::Deserialize;
+use std::{collections::HashSet, str::FromStr};
 use test_context::test_context;
 use tracing_test::traced_test;
 use utils::{
@@ -20,7 +21,7 @@ use utils::{
 use uuid::{uuid, Uuid};
 use xapi_rs::{
     adl_verb, config, resources, MyEmailAddress, MyError, MyLanguageTag, Statement, StatementIDs,
-    StatementResult, Validate, Vocabulary, CONSISTENT_THRU_HDR,
+    StatementResult, Validate, Vocabulary, CONSISTENT_THRU_HDR, PAGE_SIZE_HDR,
 };
 
 /// IMPORTANT (rsn) 20240412 - while xAPI [1] states that... "If used, an
@@ -194,6 +195,19 @@ fn test_group_w_0_agents() {
     assert!(Statement::from_str(S).is_err());
 }
 
+#[traced_test]
+#[test]
+fn test_object_w_mbox_wo_object_type() {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI mbox","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"id":"http://www.example.com/meetings/occurances/34534","mbox":"mailto:someone@adlnet.gov"}}"#;
+
+    let s = Statement::from_str(S);
+    assert!(s.is_err());
+    assert!(s.err().unwrap().to_string().contains("objectType"));
+}
+
 #[traced_test]
 #[test]
 fn test_ctx_agents_is_vec() {
@@ -1236,6 +1250,87 @@ fn test_canonical_fmt(ctx: &mut MyTestContext) -> Result<(), MyError> {
     Ok(())
 }
 
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_exact_fmt(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-GB":"attended","en-US":"attended","fr":"a assisté"}},
+"object":{
+  "objectType":"Activity",
+  "id":"http://www.example.com/meetings/occurances/34534",
+  "definition":{
+    "name":{"en-GB":"example meeting","en-US":"example meeting","fr":"réunion"},
+    "description":{
+      "en-GB":"An example meeting.",
+      "en-US":"An example meeting with certain people present."
+    }
+  }
+}}"#;
+
+    let client = &ctx.client;
+
+    let uuid = Uuid::now_v7();
+    let json = format!(r#"{{"id":"{uuid}",{}"#, &S[1..]);
+    // the stored form is the serialization of the Statement as we parsed it...
+    let stored = serde_json::to_value(Statement::from_str(&json)?).unwrap();
+
+    // 1. POST Statement w/ multi-language LMs...
+    let req = client
+        .post("/statements")
+        .body(json)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    // 2. GET it w/ exact format + an Accept-Language header that should be
+    //    ignored...
+    let req = client
+        .get(format!("/statements/?statementId={uuid}&format=exact"))
+        .header(accept_json())
+        .header(v2())
+        .header(Header::new(header::ACCEPT_LANGUAGE.as_str(), "en-US"))
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    let mut exact = resp.into_json::<serde_json::Value>().unwrap();
+    // the LRS adds `stored` and `authority`.  nothing else should change...
+    let obj = exact.as_object_mut().unwrap();
+    assert!(obj.remove("stored").is_some());
+    assert!(obj.remove("authority").is_some());
+    assert_eq!(exact, stored);
+
+    // 3. same w/ canonical format should yield a different result...
+    let req = client
+        .get(format!("/statements/?statementId={uuid}&format=canonical"))
+        .header(accept_json())
+        .header(v2())
+        .header(Header::new(header::ACCEPT_LANGUAGE.as_str(), "en-US"))
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    let mut canonical = resp.into_json::<serde_json::Value>().unwrap();
+    let obj = canonical.as_object_mut().unwrap();
+    obj.remove("stored");
+    obj.remove("authority");
+    assert_ne!(canonical, stored);
+    assert_eq!(
+        canonical["object"]["definition"]["name"],
+        serde_json::json!({"en-US": "example meeting"})
+    );
+
+    Ok(())
+}
+
 #[test_context(MyTestContext)]
 #[traced_test]
 #[test]
@@ -1326,3 +1421,1111 @@ fn test_attachment_w_file_url(ctx: &mut MyTestContext) -> Result<(), MyError> {
 
     Ok(())
 }
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_prefer_strict_handling(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    // a timestamp way into the future is fine by default but not in strict mode
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"timestamp":"2999-12-31T23:59:59.000Z"}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization())
+        .header(Header::new("Prefer", "handling=strict"));
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_related_activities_in_grouping(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"0192f1c4-5b3a-7c1e-8d2f-3a4b5c6d7e8f",
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"context":{"contextActivities":{"grouping":[{"id":"http://www.example.com/meetings/series/267"}]}}}"#;
+    const ID: Uuid = uuid!("0192f1c4-5b3a-7c1e-8d2f-3a4b5c6d7e8f");
+    const GROUPING: &str = "http://www.example.com/meetings/series/267";
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    // w/o related_activities the grouping activity should not match...
+    let req = client
+        .get(format!("/statements/?activity={}", GROUPING))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert!(sr.statements().is_empty());
+
+    // ...but w/ it, it should
+    let req = client
+        .get(format!(
+            "/statements/?activity={}&related_activities=true",
+            GROUPING
+        ))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(sr.statements().len(), 1);
+    assert_eq!(sr.statements()[0].id(), Some(&ID));
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_over_length_name(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let name = "x".repeat(config().max_name_length + 1);
+    let s = format!(
+        r#"{{
+"actor":{{"objectType":"Agent","name":"{name}","mbox":"mailto:xapi@adlnet.gov"}},
+"verb":{{"id":"http://adlnet.gov/expapi/verbs/attended","display":{{"en-US":"attended"}}}},
+"object":{{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}}}"#
+    );
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(s)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_post_json_w_charset(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(Header::new(
+            header::CONTENT_TYPE.as_str(),
+            "application/json; charset=utf-8",
+        ))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let uuids = resp
+        .into_json::<StatementIDs>()
+        .expect("Failed deserializing array of UUIDs")
+        .0;
+    assert_eq!(uuids.len(), 1);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_get_w_invalid_limit(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    let req = client
+        .get("/statements/?limit=abc")
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    // a valid one is still OK...
+    let req = client
+        .get("/statements/?limit=10")
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_attachments_order(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"9b4a5ab2-8d51-4f7e-9c55-0e0b3a1f6f2e",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/tincan/activities/multipart"},
+"attachments":[{
+    "usageType":"http://example.com/attachment-usage/test",
+    "display":{"en-US": "B test attachment"},
+    "contentType":"text/plain",
+    "length":100,
+    "sha2":"7063d0a4cfa93373753ad2f5a6ffcf684559fb1df3c2f0473a14ece7d4edb06a",
+    "fileUrl":"https://somewhere.com/there"
+},{
+    "usageType": "http://example.com/attachment-usage/test",
+    "display":{"en-US": "A test attachment" },
+    "contentType":"text/plain; charset=ascii",
+    "length":27,
+    "sha2":"495395e777cd98da653df9615d09c0fd6bb2f8d4788394cd53c56a3bfdcd848a",
+    "fileUrl":"http://somewhere.com/here"
+}]}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    let sent: Statement = serde_json::from_str(S).unwrap();
+    let expected: Vec<_> = sent.attachments().iter().map(|x| x.sha2()).collect();
+    // retrieve the Statement twice + ensure its Attachments are always in the
+    // same order as when they were submitted...
+    for _ in 0..2 {
+        let req = client
+            .get("/statements/?statementId=9b4a5ab2-8d51-4f7e-9c55-0e0b3a1f6f2e")
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+
+        let received: Statement = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+        let actual: Vec<_> = received.attachments().iter().map(|x| x.sha2()).collect();
+        assert_eq!(actual, expected);
+    }
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_more_info_is_absolute_irl(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{
+    "objectType":"Activity",
+    "id":"http://www.example.com/meetings/occurances/34534",
+    "definition":{"moreInfo":"MORE_INFO"}
+}}"#;
+
+    let client = &ctx.client;
+
+    for (more_info, status) in [
+        ("/meetings/345256", Status::BadRequest),
+        ("http://", Status::BadRequest),
+        ("http://virtualmeeting.example.com/345256", Status::Ok),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(S.replace("MORE_INFO", more_info))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status, "moreInfo: {more_info}");
+    }
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_get_stream(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/verbs/streamed","display":{"en-US":"streamed"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/streams/NDX"}}"#;
+    const VERB: &str = "http%3A%2F%2Fexample.com%2Fverbs%2Fstreamed";
+
+    let client = &ctx.client;
+
+    // 1. POST enough Statements to span several batches; w/ the default
+    //    DB_STATEMENTS_PAGE_LEN of 20 that's 3 of them...
+    let n = 43;
+    let batch: Vec<_> = (0..n).map(|i| S.replace("NDX", &i.to_string())).collect();
+    let req = client
+        .post("/statements")
+        .body(format!("[{}]", batch.join(",")))
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let mut uuids = resp
+        .into_json::<StatementIDs>()
+        .expect("Failed deserializing array of UUIDs")
+        .0;
+    assert_eq!(uuids.len(), n);
+    let posted = uuids.clone();
+
+    // 2. stream them all back; should get the same set w/o a `more` URL...
+    let req = client
+        .get(format!("/statements/stream?verb={VERB}"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    assert_eq!(resp.content_type(), Some(ContentType::JSON));
+    assert!(resp.headers().get_one(CONSISTENT_THRU_HDR).is_some());
+    assert!(resp.headers().get_one(header::ETAG.as_str()).is_none());
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert!(sr.more().is_none());
+    let mut received: Vec<_> = sr.statements().iter().map(|x| *x.id().unwrap()).collect();
+    uuids.sort();
+    received.sort();
+    assert_eq!(received, uuids);
+
+    // 3. Statements POSTed together share the same `stored` timestamp; they
+    //    still come back once each, and in insertion order, across batches...
+    let req = client
+        .get(format!("/statements/stream?verb={VERB}&ascending=true"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    let received: Vec<_> = sr.statements().iter().map(|x| *x.id().unwrap()).collect();
+    assert_eq!(received, posted);
+
+    // 4. a `limit` still caps the number of streamed Statements...
+    let req = client
+        .get(format!("/statements/stream?verb={VERB}&limit=5"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(sr.statements().len(), 5);
+
+    // 5. single Statements can't be streamed...
+    let req = client
+        .get(format!("/statements/stream?statementId={}", uuids[0]))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
+/// A group of Equivalent Statements and the fingerprint they all share.
+#[derive(Deserialize)]
+struct FingerprintGroup {
+    uid: u64,
+    statements: Vec<Statement>,
+}
+
+#[test]
+fn test_fingerprint_stability() {
+    // each item is a group of Statements that MUST be Equivalent to each other
+    // and NOT Equivalent to those of any other group.  the expected `uid` is
+    // committed w/ the fixture so any change to the fingerprint algorithm (and
+    // hence to the `fp` values already stored in the DB) is caught here...
+    let json = read_to_string("fingerprints", true);
+    let groups: Vec<FingerprintGroup> = serde_json::from_str(&json).unwrap();
+    assert_eq!(groups.len(), 4);
+
+    let mut uids = vec![];
+    for (i, group) in groups.iter().enumerate() {
+        let uid = group.uid;
+        for (j, s) in group.statements.iter().enumerate() {
+            assert_eq!(s.uid(), uid, "Statement #{j} in group #{i}");
+            // serialization round-trips should not alter the fingerprint...
+            let s2: Statement = serde_json::from_str(&serde_json::to_string(s).unwrap()).unwrap();
+            assert_eq!(s2.uid(), uid, "Round-tripped Statement #{j} in group #{i}");
+        }
+        assert!(!uids.contains(&uid), "Group #{i} collides w/ another group");
+        uids.push(uid);
+    }
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_page_size_header(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/verbs/paged","display":{"en-US":"paged"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/pages/NDX"}}"#;
+
+    let client = &ctx.client;
+
+    // 1. POST 5 Statements...
+    let batch: Vec<_> = (0..5).map(|i| S.replace("NDX", &i.to_string())).collect();
+    let req = client
+        .post("/statements")
+        .body(format!("[{}]", batch.join(",")))
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    // 2. fetch them 2 at a time following `more` URLs...
+    let mut url = "/statements/?verb=http%3A%2F%2Fexample.com%2Fverbs%2Fpaged".to_owned();
+    let mut sizes = vec![];
+    loop {
+        let req = client
+            .get(&url)
+            .header(Header::new(PAGE_SIZE_HDR, "2"))
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+        sizes.push(sr.statements().len());
+        match sr.more() {
+            Some(x) => url = x.as_str().replace(&config().external_url, ""),
+            None => break,
+        }
+    }
+    assert_eq!(sizes, vec![2, 2, 1]);
+
+    // 3. an explicit `limit=0`, meaning the server's maximum, wins over the
+    //    header...
+    let req = client
+        .get("/statements/?verb=http%3A%2F%2Fexample.com%2Fverbs%2Fpaged&limit=0")
+        .header(Header::new(PAGE_SIZE_HDR, "2"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    let expected = 5.min(config().db_statements_page_len as usize);
+    assert_eq!(sr.statements().len(), expected);
+
+    // 4. a malformed value is rejected...
+    let req = client
+        .get("/statements/")
+        .header(Header::new(PAGE_SIZE_HDR, "two"))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_ascending_more(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/verbs/ascended","display":{"en-US":"ascended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/steps/NDX"}}"#;
+
+    let client = &ctx.client;
+
+    // 1. POST 5 Statements one at a time...
+    let mut expected = vec![];
+    for i in 0..5 {
+        let req = client
+            .post("/statements")
+            .body(S.replace("NDX", &i.to_string()))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        let ids: Vec<Uuid> = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+        expected.extend(ids);
+    }
+
+    // 2. fetch them, oldest first, 2 at a time following `more` URLs...
+    let mut url =
+        "/statements/?verb=http%3A%2F%2Fexample.com%2Fverbs%2Fascended&ascending=true".to_owned();
+    let mut actual = vec![];
+    loop {
+        let req = client
+            .get(&url)
+            .header(Header::new(PAGE_SIZE_HDR, "2"))
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+        actual.extend(sr.statements().iter().map(|x| *x.id().unwrap()));
+        match sr.more() {
+            Some(x) => {
+                assert!(x.as_str().contains("ascending=true"));
+                url = x.as_str().replace(&config().external_url, "")
+            }
+            None => break,
+        }
+    }
+    assert_eq!(actual, expected);
+
+    Ok(())
+}
+
+/// W/ REJECT_CLIENT_STORED off (the default), a Statement w/ a `stored`
+/// property is persisted w/ an LRS-assigned one. See `flags.rs` for when
+/// it's on.
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_client_stored(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"01928d4f-487c-7a72-a38b-f5097c07d2aa",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"stored":"2013-05-18T05:32:34.804Z"}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    let client_stored = DateTime::parse_from_rfc3339("2013-05-18T05:32:34.804Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let req = client
+        .get("/statements/?statementId=01928d4f-487c-7a72-a38b-f5097c07d2aa")
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let received: Statement = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    let stored = received.stored().expect("Missing 'stored'");
+    assert!(*stored > client_stored);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_nil_statement_ref(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"StatementRef","id":"00000000-0000-0000-0000-000000000000"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    // should fail b/c a nil UUID can't identify a Statement...
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_invalid_context_language(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"context":{"language":"not a tag!!"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    // should fail b/c `language` is not a valid BCP-47 tag...
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_resumable_upload_disabled(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    let contents = "resumable uploads are off by default";
+    let sha2 = hex::encode(openssl::sha::sha256(contents.as_bytes()));
+    let req = client
+        .put(format!("/statements/attachments/{sha2}"))
+        .body(contents)
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::NotFound);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_identified_group_authority(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"xAPI account","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-GB":"attended"}},
+"authority":{
+  "objectType":"Group",
+  "mbox":"mailto:authorities@example.com",
+  "member":[
+    {"account":{"homePage":"http://example.com/xAPI/OAuth/Token","name":"oauth_consumer_x75db"}},
+    {"mbox":"mailto:bob_authority@example.com"}
+  ]},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+    let body = resp.into_string().unwrap();
+    assert!(body.contains("a Group must be anonymous w/ exactly 2 members"));
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_empty_attachment_display(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"mbox":"mailto:sample.agent@example.com","name":"Sample Agent","objectType":"Agent"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{"id":"http://www.example.com/tincan/activities/multipart","objectType":"Activity"},
+"attachments":[{
+    "usageType":"http://example.com/attachment-usage/test",
+    "display":DISPLAY,
+    "contentType":"text/plain; charset=ascii",
+    "length":27,
+    "sha2":"495395e777cd98da653df9615d09c0fd6bb2f8d4788394cd53c56a3bfdcd848a",
+    "fileUrl":"http://www.example.com/attachments/test.txt"
+}]}"#;
+
+    let client = &ctx.client;
+
+    for (display, status) in [
+        ("{}", Status::BadRequest),
+        (r#"{"en-US":"A test attachment"}"#, Status::Ok),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(S.replace("DISPLAY", display))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_substatement_attachment(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"mbox":"mailto:sample.agent@example.com","name":"Sample Agent","objectType":"Agent"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{
+    "objectType":"SubStatement",
+    "actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+    "verb":{"id":"http://adlnet.gov/expapi/verbs/reported","display":{"en-US":"reported"}},
+    "object":{"id":"http://www.example.com/tincan/activities/multipart","objectType":"Activity"},
+    "attachments":[{
+        "usageType":"http://example.com/attachment-usage/test",
+        "display":{"en-US":"A test attachment"},
+        "contentType":"CT",
+        "length":LEN,
+        "sha2":"SHA2",
+        "fileUrl":"http://www.example.com/attachments/test.txt"
+    }]}}"#;
+    const SHA2: &str = "495395e777cd98da653df9615d09c0fd6bb2f8d4788394cd53c56a3bfdcd848a";
+
+    let client = &ctx.client;
+
+    for (ct, len, sha2, status) in [
+        ("text/plain", "27", SHA2, Status::Ok),
+        ("text/plain", "27", "not-a-sha2", Status::BadRequest),
+        ("text/plain", "0", SHA2, Status::BadRequest),
+        ("", "27", SHA2, Status::BadRequest),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(
+                S.replace("CT", ct)
+                    .replace("LEN", len)
+                    .replace("SHA2", sha2),
+            )
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_payload_too_large(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    // an otherwise empty batch padded w/ whitespace past the configured limit...
+    let limit = config().json_limit.as_u64() as usize;
+    let body = format!("[{}]", " ".repeat(limit));
+    let req = client
+        .post("/statements")
+        .body(body)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::PayloadTooLarge);
+    let msg = resp.into_string().unwrap();
+    assert!(msg.contains(&config().json_limit.to_string()));
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_empty_correct_responses_pattern(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{
+    "objectType":"Activity",
+    "id":"http://www.example.com/quiz/question/1",
+    "definition":{
+        "type":"http://adlnet.gov/expapi/activities/cmi.interaction",
+        "interactionType":"true-false",
+        "correctResponsesPattern":CRP}}}"#;
+
+    let client = &ctx.client;
+
+    for (crp, status) in [("[]", Status::BadRequest), (r#"["true"]"#, Status::Ok)] {
+        let req = client
+            .post("/statements")
+            .body(S.replace("CRP", crp))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_bad_registration(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"context":{"registration":"REGISTRATION"}}"#;
+
+    let client = &ctx.client;
+
+    for (registration, status) in [
+        ("00000000-0000-0000-0000-000000000000", Status::BadRequest),
+        ("ffffffff-ffff-ffff-ffff-ffffffffffff", Status::BadRequest),
+        ("not-a-uuid", Status::BadRequest),
+        ("ec531277-b57b-4c15-8d91-d292c5b2b8f7", Status::Ok),
+    ] {
+        let req = client
+            .post("/statements")
+            .body(S.replace("REGISTRATION", registration))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+/// W/ REJECT_DUPLICATE_KEYS off (the default), a Statement w/ a duplicated key
+/// is persisted w/ the last value of that key. See `flags.rs` for when it's on.
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_duplicate_keys(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/answered","display":{"en-US":"answered"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let ids: Vec<Uuid> = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+
+    let req = client
+        .get(format!("/statements/?statementId={}", ids[0]))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let received: Statement = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(
+        received.verb().id_as_str(),
+        "http://adlnet.gov/expapi/verbs/answered"
+    );
+
+    Ok(())
+}
+
+/// When filtering by both `agent` and `registration` only Statements that
+/// match both, incl. those whose actor is a Group w/ the Agent as a member,
+/// are returned.
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_agent_and_registration(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const AGENT: &str = r#"{"objectType":"Agent","mbox":"mailto:and.agent@example.com"}"#;
+    // same as AGENT but percent-encoded...
+    const AGENT_QP: &str = "%7B%22objectType%22%3A%22Agent%22%2C%22mbox%22%3A%22mailto%3Aand.agent%40example.com%22%7D";
+    const OTHER: &str = r#"{"objectType":"Agent","mbox":"mailto:other.agent@example.com"}"#;
+    const GROUP: &str = r#"{"objectType":"Group","name":"Anonymous","member":[AGENT]}"#;
+    const REGISTRATION: &str = "a5b6c7d8-1234-4abc-9def-0123456789ab";
+    const S: &str = r#"{
+"actor":ACTOR,
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended","display":{"en-US":"attended"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/NDX"}CONTEXT}"#;
+
+    let client = &ctx.client;
+
+    let group = GROUP.replace("AGENT", AGENT);
+    let context = format!(r#","context":{{"registration":"{REGISTRATION}"}}"#);
+    let context = context.as_str();
+    let batch: Vec<_> = [
+        (AGENT, ""),               // agent only
+        (OTHER, context),          // registration only
+        (AGENT, context),          // both
+        (group.as_str(), context), // both, as a member
+        (group.as_str(), ""),      // agent only, as a member
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, (actor, context))| {
+        S.replace("ACTOR", actor)
+            .replace("NDX", &i.to_string())
+            .replace("CONTEXT", context)
+    })
+    .collect();
+    let req = client
+        .post("/statements")
+        .body(format!("[{}]", batch.join(",")))
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let ids: Vec<Uuid> = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(ids.len(), 5);
+
+    let req = client
+        .get(format!(
+            "/statements/?agent={AGENT_QP}&registration={REGISTRATION}"
+        ))
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    let actual: HashSet<Uuid> = sr.statements().iter().map(|x| *x.id().unwrap()).collect();
+    let expected = HashSet::from([ids[2], ids[3]]);
+    assert_eq!(actual, expected);
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_deep_extension(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","mbox":"mailto:xapi@adlnet.gov"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/attended"},
+"object":{"objectType":"Activity","id":"http://www.example.com/meetings/occurances/34534"},
+"context":{"extensions":{"http://example.com/ext/deep":EXT}}}"#;
+
+    let client = &ctx.client;
+
+    // statement, context and extensions account for 3 levels. the rejected
+    // depth stays below serde_json's own limit of 128 so it's our check that
+    // rejects it...
+    let max = config().max_json_depth;
+    assert!(
+        max > 3 && max < 127,
+        "MAX_JSON_DEPTH out of range for this test"
+    );
+    for (depth, status) in [(max - 3, Status::Ok), (max - 2, Status::BadRequest)] {
+        let ext = format!("{}null{}", "[".repeat(depth), "]".repeat(depth));
+        let req = client
+            .post("/statements")
+            .body(S.replace("EXT", &ext))
+            .header(ContentType::JSON)
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), status);
+    }
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_agent_not_json(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    let req = client
+        .get("/statements/?agent=not-json")
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+    let msg = resp.into_string().unwrap();
+    assert!(msg.contains("Invalid 'agent' parameter"));
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_no_more(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/verbs/unpaged","display":{"en-US":"unpaged"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/pages/1"}}"#;
+
+    let client = &ctx.client;
+
+    let req = client
+        .post("/statements")
+        .body(S)
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+
+    // a result that fits in 1 page has no `more` property at all...
+    let req = client
+        .get("/statements/?verb=http%3A%2F%2Fexample.com%2Fverbs%2Funpaged")
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let json: serde_json::Value = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(json["statements"].as_array().map(|x| x.len()), Some(1));
+    assert!(json.get("more").is_none());
+
+    Ok(())
+}
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_batched_insert(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    const S: &str = r#"{
+"id":"ID",
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://example.com/verbs/batched","display":{"en-US":"batched"}},
+"object":{"objectType":"Activity","id":"http://www.example.com/batches/NDX"}}"#;
+    const V: &str = r#"{
+"actor":{"objectType":"Agent","name":"Sample Agent","mbox":"mailto:sample.agent@example.com"},
+"verb":{"id":"http://adlnet.gov/expapi/verbs/voided","display":{"en-US":"voided"}},
+"object":{"objectType":"StatementRef","id":"ID"}}"#;
+    const URL: &str = "/statements/?verb=http%3A%2F%2Fexample.com%2Fverbs%2Fbatched";
+
+    let client = &ctx.client;
+    let fetch_all = || {
+        let req = client
+            .get(URL)
+            .header(Header::new(PAGE_SIZE_HDR, "100"))
+            .header(accept_json())
+            .header(v2())
+            .header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        let sr: StatementResult = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+        sr.statements()
+            .iter()
+            .map(|x| *x.id().unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    // 1. POST 100 Statements; more than the default batch size...
+    let uuids: Vec<Uuid> = (0..100).map(|_| Uuid::now_v7()).collect();
+    let batch: Vec<_> = uuids
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            S.replace("ID", &x.to_string())
+                .replace("NDX", &i.to_string())
+        })
+        .collect();
+    let req = client
+        .post("/statements")
+        .body(format!("[{}]", batch.join(",")))
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let ids: Vec<Uuid> = serde_json::from_str(&resp.into_string().unwrap()).unwrap();
+    assert_eq!(ids, uuids);
+
+    // 2. they're all retrievable, most recent first...
+    let mut expected = uuids.clone();
+    expected.reverse();
+    assert_eq!(fetch_all(), expected);
+
+    // 3. voiding one of them still works...
+    let req = client
+        .post("/statements")
+        .body(V.replace("ID", &uuids[42].to_string()))
+        .header(ContentType::JSON)
+        .header(accept_json())
+        .header(v2())
+        .header(authorization());
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let found = fetch_all();
+    assert!(!found.contains(&uuids[42]));
+    for (i, x) in uuids.iter().enumerate() {
+        assert!(i == 42 || found.contains(x));
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 mod utils;
 
-use rocket::http::{hyper::header, ContentType, Status};
+use rocket::http::{hyper::header, ContentType, Header, Status};
 use test_context::test_context;
 use tracing::debug;
 use tracing_test::traced_test;
@@ -683,3 +683,43 @@ fn test_batch_update(ctx: &mut MyTestContext) -> Result<(), MyError> {
 
     Ok(())
 }
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_challenge(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    skip_if_legacy!();
+
+    let client = &ctx.client;
+
+    // 1. w/o credentials...
+    let req = client.get("/statements").header(accept_json()).header(v2());
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Unauthorized);
+    let challenge = resp.headers().get_one("WWW-Authenticate");
+    assert_eq!(challenge, Some(r#"Basic realm="LaRS""#));
+
+    // 2. w/ unknown credentials...
+    let req = client
+        .get("/statements")
+        .header(accept_json())
+        .header(v2())
+        .header(act_as("nobody@testing.xapi", "password"));
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::Unauthorized);
+    assert!(resp.headers().contains("WWW-Authenticate"));
+
+    // 3. a malformed Authorization header is a bad request...
+    let req = client
+        .get("/statements")
+        .header(accept_json())
+        .header(v2())
+        .header(Header::new(header::AUTHORIZATION.as_str(), "Nope"));
+
+    let resp = req.dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+
+    Ok(())
+}
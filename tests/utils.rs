@@ -4,7 +4,7 @@
 
 use base64::{prelude::BASE64_STANDARD, Engine};
 use rocket::http::{hyper::header, ContentType, Header};
-use std::{fs, path::PathBuf};
+use std::{env, fs, path::PathBuf, sync::Once};
 use xapi_rs::{TEST_USER_PLAIN_TOKEN, V200, VERSION_HDR};
 
 pub(crate) const BOUNDARY: &str = "MP_/xq.2QWbNf.dRrz_w=FAz9Dd";
@@ -67,6 +67,22 @@ impl test_context::TestContext for MyTestContext {
     }
 }
 
+/// Set the given environment variables before the LRS configuration is read.
+///
+/// That configuration is read once per process. So this is only useful in
+/// test binaries dedicated to exercising non-default configuration values,
+/// where every test calls it, w/ the same `vars`, before anything else.
+pub(crate) fn set_env_once(vars: &[(&str, &str)]) {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        for (k, v) in vars {
+            // SAFETY: other tests in the same binary block on `ONCE` before
+            // they get a chance to read the environment...
+            unsafe { env::set_var(k, v) }
+        }
+    });
+}
+
 pub(crate) fn accept_json() -> Header<'static> {
     Header::new(header::ACCEPT.as_str(), "application/json")
 }
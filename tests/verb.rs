@@ -300,3 +300,37 @@ fn test_extension(ctx: &mut MyTestContext) -> Result<(), MyError> {
 
     Ok(())
 }
+
+#[test_context(MyTestContext)]
+#[traced_test]
+#[test]
+fn test_default_order(ctx: &mut MyTestContext) -> Result<(), MyError> {
+    let client = &ctx.client;
+
+    let rids = |url: &str| {
+        let req = client.get(url).header(v2()).header(authorization());
+        let resp = req.dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        resp.into_json::<Vec<VerbUI>>()
+            .expect("Failed deserializing VerbUI list")
+            .iter()
+            .map(|x| x.rid())
+            .collect::<Vec<_>>()
+    };
+
+    // w/o an 'asc' parameter Verbs are listed by ascending row ID...
+    let list = rids("/extensions/verbs/?language=en&count=10");
+    assert!(list.len() > 1);
+    assert!(list.windows(2).all(|x| x[0] < x[1]));
+    assert_eq!(
+        list,
+        rids("/extensions/verbs/?language=en&count=10&asc=true")
+    );
+
+    // ...unless told otherwise...
+    let list = rids("/extensions/verbs/?language=en&count=10&asc=false");
+    assert!(list.len() > 1);
+    assert!(list.windows(2).all(|x| x[0] > x[1]));
+
+    Ok(())
+}